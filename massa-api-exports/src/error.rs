@@ -51,6 +51,8 @@ pub enum ApiError {
     BadRequest(String),
     /// Internal server error: {0}
     InternalServerError(String),
+    /// Operation fee too low: {0}
+    FeeTooLow(String),
 }
 
 impl From<ApiError> for JsonRpseeError {
@@ -74,6 +76,7 @@ impl From<ApiError> for JsonRpseeError {
             ApiError::MissingCommandSender(_) => -32017,
             ApiError::MissingConfig(_) => -32018,
             ApiError::WrongAPI => -32019,
+            ApiError::FeeTooLow(_) => -32020,
         };
 
         CallError::Custom(ErrorObject::owned(code, err.to_string(), None::<()>)).into()
@@ -84,4 +84,7 @@ pub struct ReadOnlyCall {
     /// whether to start execution from final or active state. Default false
     #[serde(default)]
     pub is_final: bool,
+    /// whether to reject ledger writes and coin transfers, for view calls. Default false
+    #[serde(default)]
+    pub view_only: bool,
 }
@@ -1,7 +1,7 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_models::node::NodeId;
-use massa_models::stats::{ConsensusStats, ExecutionStats, NetworkStats};
+use massa_models::stats::{ConsensusStats, ExecutionStats, NetworkStats, PoolStats};
 use massa_models::{config::CompactConfig, slot::Slot, version::Version};
 use massa_time::MassaTime;
 use serde::{Deserialize, Serialize};
@@ -33,8 +33,8 @@ pub struct NodeStatus {
     pub next_slot: Slot,
     /// consensus stats
     pub consensus_stats: ConsensusStats,
-    /// pool stats (operation count and endorsement count)
-    pub pool_stats: (usize, usize),
+    /// pool stats
+    pub pool_stats: PoolStats,
     /// network stats
     pub network_stats: NetworkStats,
     /// execution stats
@@ -67,10 +67,7 @@ impl std::fmt::Display for NodeStatus {
 
         writeln!(f, "{}", self.consensus_stats)?;
 
-        writeln!(f, "Pool stats:")?;
-        writeln!(f, "\tOperations count: {}", self.pool_stats.0)?;
-        writeln!(f, "\tEndorsements count: {}", self.pool_stats.1)?;
-        writeln!(f)?;
+        writeln!(f, "{}", self.pool_stats)?;
 
         writeln!(f, "{}", self.network_stats)?;
 
@@ -176,8 +176,10 @@ impl MassaRpcServer for API<Public> {
                     coins: Default::default(),
                     owned_addresses: vec![address],
                     operation_datastore: op_datastore,
+                    is_read_only: false,
                 }],
                 is_final,
+                view_only: false,
             };
 
             // run
@@ -222,6 +224,7 @@ impl MassaRpcServer for API<Public> {
             parameter,
             caller_address,
             is_final,
+            view_only,
         } in reqs
         {
             let caller_address = caller_address.unwrap_or_else(|| {
@@ -248,15 +251,18 @@ impl MassaRpcServer for API<Public> {
                         coins: Default::default(),
                         owned_addresses: vec![caller_address],
                         operation_datastore: None, // should always be None
+                        is_read_only: false,
                     },
                     ExecutionStackElement {
                         address: target_address,
                         coins: Default::default(),
                         owned_addresses: vec![target_address],
                         operation_datastore: None, // should always be None
+                        is_read_only: false,
                     },
                 ],
                 is_final,
+                view_only,
             };
 
             // run
@@ -357,10 +363,7 @@ impl MassaRpcServer for API<Public> {
             Err(e) => return Err(ApiError::NetworkError(e).into()),
         };
 
-        let pool_stats = (
-            pool_command_sender.get_operation_count(),
-            pool_command_sender.get_endorsement_count(),
-        );
+        let pool_stats = pool_command_sender.get_pool_stats();
 
         let next_slot_result = last_slot
             .unwrap_or_else(|| Slot::new(0, 0))
@@ -952,6 +955,13 @@ impl MassaRpcServer for API<Public> {
                         Ok(()) => (),
                         Err(e) => return Err(ApiError::ModelsError(e).into()),
                     };
+                    if operation.content.fee < api_cfg.min_fee {
+                        return Err(ApiError::FeeTooLow(format!(
+                            "operation {} pays a fee of {} but the minimum fee is {}",
+                            operation.id, operation.content.fee, api_cfg.min_fee
+                        ))
+                        .into());
+                    }
                     Ok(operation)
                 }
                 Err(e) => Err(e),
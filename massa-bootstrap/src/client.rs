@@ -486,6 +486,9 @@ pub async fn get_state(
     let mut unique_node_ids: HashSet<NodeId> = HashSet::new();
     filtered_bootstrap_list.retain(|e| unique_node_ids.insert(e.1));
 
+    // The streaming cursors of the last received final state part are kept in `next_bootstrap_message`
+    // across connection attempts, so that a failed bootstrap resumes from them instead of restarting from scratch.
+    // They are only reset when the server answers that the cursor slot is too old.
     let mut next_bootstrap_message: BootstrapClientMessage =
         BootstrapClientMessage::AskBootstrapPart {
             last_slot: None,
@@ -506,6 +509,16 @@ pub async fn get_state(
                 }
             }
             info!("Start bootstrapping from {}", addr);
+            if let BootstrapClientMessage::AskBootstrapPart {
+                last_slot: Some(last_slot),
+                ..
+            } = &next_bootstrap_message
+            {
+                info!(
+                    "Resuming final state bootstrap from the cursors received at slot {}",
+                    last_slot
+                );
+            }
             match connect_to_server(
                 &mut establisher,
                 bootstrap_config,
@@ -14,7 +14,7 @@ pub mod types {
 #[cfg(not(test))]
 /// Connection types
 pub mod types {
-    use crate::tools::normalize_ip;
+    use crate::tools::{check_ip_lists, normalize_ip};
     use massa_time::MassaTime;
     use std::{
         collections::HashSet,
@@ -47,16 +47,7 @@ pub mod types {
         ) -> io::Result<(Duplex, SocketAddr)> {
             // accept
             let (sock, mut remote_addr) = self.0.accept().await?;
-            let ip = normalize_ip(remote_addr.ip());
-            if let Some(blacklist) = blacklist && blacklist.contains(&ip) {
-                return Err(io::Error::new(io::ErrorKind::Other, "IP is blacklisted"));
-            }
-            if let Some(whitelist) = whitelist && !whitelist.contains(&ip) {
-                return Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    "A whitelist exists and the IP is not whitelisted",
-                ));
-            }
+            check_ip_lists(&normalize_ip(remote_addr.ip()), whitelist, blacklist)?;
             // normalize address
             remote_addr.set_ip(remote_addr.ip().to_canonical());
             Ok((sock, remote_addr))
@@ -35,9 +35,20 @@ use crate::{
 pub struct BootstrapManager {
     join_handle: JoinHandle<Result<(), BootstrapError>>,
     manager_tx: mpsc::Sender<()>,
+    lists_reload_tx: mpsc::Sender<()>,
 }
 
 impl BootstrapManager {
+    /// Ask the bootstrap server to reload its whitelist and blacklist files
+    /// without waiting for the next periodic reload.
+    /// The previous lists are kept if the files can't be parsed.
+    pub async fn reload_lists(&self) {
+        massa_trace!("bootstrap.lib.reload_lists", {});
+        if self.lists_reload_tx.send(()).await.is_err() {
+            warn!("bootstrap server already dropped");
+        }
+    }
+
     /// stop the bootstrap server
     pub async fn stop(self) -> Result<(), BootstrapError> {
         massa_trace!("bootstrap.lib.stop", {});
@@ -66,6 +77,7 @@ pub async fn start_bootstrap_server(
     massa_trace!("bootstrap.lib.start_bootstrap_server", {});
     if let Some(bind) = bootstrap_config.bind {
         let (manager_tx, manager_rx) = mpsc::channel::<()>(1);
+        let (lists_reload_tx, lists_reload_rx) = mpsc::channel::<()>(1);
 
         let join_handle = tokio::spawn(async move {
             BootstrapServer {
@@ -74,6 +86,7 @@ pub async fn start_bootstrap_server(
                 final_state,
                 establisher,
                 manager_rx,
+                lists_reload_rx,
                 bind,
                 keypair,
                 version,
@@ -86,6 +99,7 @@ pub async fn start_bootstrap_server(
         Ok(Some(BootstrapManager {
             join_handle,
             manager_tx,
+            lists_reload_tx,
         }))
     } else {
         Ok(None)
@@ -98,6 +112,7 @@ struct BootstrapServer {
     final_state: Arc<RwLock<FinalState>>,
     establisher: Establisher,
     manager_rx: mpsc::Receiver<()>,
+    lists_reload_rx: mpsc::Receiver<()>,
     bind: SocketAddr,
     keypair: KeyPair,
     bootstrap_config: BootstrapConfig,
@@ -107,7 +122,7 @@ struct BootstrapServer {
 
 #[allow(clippy::result_large_err)]
 #[allow(clippy::type_complexity)]
-fn reload_whitelist_blacklist(
+pub(crate) fn reload_whitelist_blacklist(
     whitelist_path: &PathBuf,
     blacklist_path: &PathBuf,
 ) -> Result<(Option<HashSet<IpAddr>>, Option<HashSet<IpAddr>>), BootstrapError> {
@@ -146,6 +161,22 @@ fn reload_whitelist_blacklist(
 }
 
 impl BootstrapServer {
+    /// Reload the whitelist and the blacklist from their files.
+    /// Both lists are swapped at once, and kept unchanged if any of the files can't be parsed.
+    fn reload_lists(
+        &self,
+        whitelist: &mut Option<HashSet<IpAddr>>,
+        blacklist: &mut Option<HashSet<IpAddr>>,
+    ) {
+        match reload_whitelist_blacklist(
+            &self.bootstrap_config.bootstrap_whitelist_path,
+            &self.bootstrap_config.bootstrap_blacklist_path,
+        ) {
+            Ok(lists) => (*whitelist, *blacklist) = lists,
+            Err(err) => warn!("could not reload bootstrap whitelist and blacklist: {}", err),
+        }
+    }
+
     pub async fn run(mut self) -> Result<(), BootstrapError> {
         debug!("starting bootstrap server");
         massa_trace!("bootstrap.lib.run", {});
@@ -176,9 +207,13 @@ impl BootstrapServer {
                     break
                 },
 
-                // Whitelist cache timeout
+                // Whitelist cache timeout or reload request
                 _ = cache_interval.tick() => {
-                    (whitelist, blacklist) = reload_whitelist_blacklist(&self.bootstrap_config.bootstrap_whitelist_path, &self.bootstrap_config.bootstrap_blacklist_path)?;
+                    self.reload_lists(&mut whitelist, &mut blacklist);
+                }
+                Some(_) = self.lists_reload_rx.recv() => {
+                    massa_trace!("bootstrap.lib.run.select.reload_lists", {});
+                    self.reload_lists(&mut whitelist, &mut blacklist);
                 }
 
                 // bootstrap session finished
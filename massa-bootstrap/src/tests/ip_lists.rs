@@ -0,0 +1,33 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+use crate::{
+    server::reload_whitelist_blacklist,
+    tools::{check_ip_lists, normalize_ip},
+};
+use std::{net::IpAddr, str::FromStr};
+use tempfile::TempDir;
+
+#[test]
+fn test_blacklist_reload() {
+    let temp_dir = TempDir::new().unwrap();
+    let whitelist_path = temp_dir.path().join("bootstrap_whitelist.json");
+    let blacklist_path = temp_dir.path().join("bootstrap_blacklist.json");
+    let ip = normalize_ip(IpAddr::from_str("192.168.0.12").unwrap());
+
+    // no whitelist and an empty blacklist: the IP is accepted
+    std::fs::write(&blacklist_path, "[]").unwrap();
+    let (whitelist, blacklist) =
+        reload_whitelist_blacklist(&whitelist_path, &blacklist_path).unwrap();
+    assert!(whitelist.is_none());
+    assert!(check_ip_lists(&ip, &whitelist, &blacklist).is_ok());
+
+    // the IP is added to the blacklist: it is rejected after reload
+    std::fs::write(&blacklist_path, "[\"192.168.0.12\"]").unwrap();
+    let (whitelist, blacklist) =
+        reload_whitelist_blacklist(&whitelist_path, &blacklist_path).unwrap();
+    assert!(check_ip_lists(&ip, &whitelist, &blacklist).is_err());
+
+    // an invalid file is reported instead of silently clearing the lists
+    std::fs::write(&blacklist_path, "[\"192.168.0").unwrap();
+    assert!(reload_whitelist_blacklist(&whitelist_path, &blacklist_path).is_err());
+}
@@ -1,6 +1,7 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 mod binders;
+mod ip_lists;
 pub mod mock_establisher;
 mod scenarios;
 pub mod tools;
@@ -8,7 +8,8 @@ use super::{
     },
 };
 use crate::tests::tools::{
-    get_random_async_pool_changes, get_random_executed_ops_changes, get_random_pos_changes,
+    get_deterministic_address, get_deterministic_public_key, get_random_async_pool_changes,
+    get_random_executed_ops_changes, get_random_pos_changes,
 };
 use crate::BootstrapConfig;
 use crate::{
@@ -354,3 +355,13 @@ async fn test_bootstrap_server() {
     server_selector_manager.stop();
     client_selector_manager.stop();
 }
+
+#[test]
+fn test_deterministic_test_addresses() {
+    assert_eq!(get_deterministic_address(3), get_deterministic_address(3));
+    assert_eq!(
+        get_deterministic_public_key(3),
+        get_deterministic_public_key(3)
+    );
+    assert_ne!(get_deterministic_address(3), get_deterministic_address(4));
+}
@@ -38,7 +38,7 @@ use massa_models::{
     block_id::BlockId,
     endorsement::Endorsement,
     endorsement::EndorsementSerializer,
-    operation::OperationId,
+    operation::{compute_operations_merkle_root, OperationId},
     prehash::PreHashMap,
     secure_share::Id,
     secure_share::SecureShareContent,
@@ -47,7 +47,7 @@ use massa_models::{
 use massa_network_exports::{BootstrapPeers, NetworkCommand};
 use massa_pos_exports::{CycleInfo, DeferredCredits, PoSChanges, PoSFinalState, ProductionStats};
 use massa_serialization::{DeserializeError, Deserializer, Serializer};
-use massa_signature::{KeyPair, PublicKey, Signature};
+use massa_signature::{test_exports::deterministic_keypair, KeyPair, PublicKey, Signature};
 use massa_time::MassaTime;
 use rand::Rng;
 use std::collections::{HashMap, VecDeque};
@@ -270,6 +270,16 @@ pub fn get_random_address() -> Address {
     Address::from_public_key(&priv_key.get_public_key())
 }
 
+/// Same as `get_random_public_key` but always returns the same public key for a given `index`
+pub fn get_deterministic_public_key(index: u64) -> PublicKey {
+    deterministic_keypair(index).get_public_key()
+}
+
+/// Same as `get_random_address` but always returns the same address for a given `index`
+pub fn get_deterministic_address(index: u64) -> Address {
+    Address::from_public_key(&get_deterministic_public_key(index))
+}
+
 pub fn get_dummy_signature(s: &str) -> Signature {
     let priv_key = KeyPair::generate();
     priv_key.sign(&Hash::compute_from(s.as_bytes())).unwrap()
@@ -381,7 +391,7 @@ pub fn get_boot_state() -> BootstrapableGraph {
                     // all header endorsements are supposed to point towards this one
                     slot: Slot::new(1, 0),
                     parents: vec![get_dummy_block_id("p1"); THREAD_COUNT as usize],
-                    operation_merkle_root: Hash::compute_from("op_hash".as_bytes()),
+                    operation_merkle_root: compute_operations_merkle_root(&[]),
                     endorsements: vec![
                         Endorsement::new_verifiable(
                             Endorsement {
@@ -1,4 +1,4 @@
-use std::net::IpAddr;
+use std::{collections::HashSet, io, net::IpAddr};
 
 /// Why not just to_canonical ?
 /// Because the case in which the incoming ip is ipv4 but was mapped to ipv6 by the os,
@@ -11,3 +11,22 @@ pub(crate) fn normalize_ip(ip: IpAddr) -> IpAddr {
     }
     .to_canonical()
 }
+
+/// Checks that an incoming IP is allowed to bootstrap according to the whitelist and the blacklist.
+/// The IP is expected to be normalized (see `normalize_ip`).
+pub(crate) fn check_ip_lists(
+    ip: &IpAddr,
+    whitelist: &Option<HashSet<IpAddr>>,
+    blacklist: &Option<HashSet<IpAddr>>,
+) -> io::Result<()> {
+    if let Some(blacklist) = blacklist && blacklist.contains(ip) {
+        return Err(io::Error::new(io::ErrorKind::Other, "IP is blacklisted"));
+    }
+    if let Some(whitelist) = whitelist && !whitelist.contains(ip) {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            "A whitelist exists and the IP is not whitelisted",
+        ));
+    }
+    Ok(())
+}
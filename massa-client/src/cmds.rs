@@ -1173,6 +1173,7 @@ impl Command {
                         parameter,
                         max_gas,
                         is_final,
+                        view_only: false,
                     })
                     .await
                 {
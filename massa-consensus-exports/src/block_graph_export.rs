@@ -7,6 +7,7 @@ use massa_models::{
 };
 
 use crate::block_status::{DiscardReason, ExportCompiledBlock};
+use crate::error::ConsensusError;
 
 /// Bootstrap compatible version of the block graph
 #[derive(Debug, Clone)]
@@ -27,3 +28,42 @@ pub struct BlockGraphExport {
     /// List of maximal cliques of compatible blocks.
     pub max_cliques: Vec<Clique>,
 }
+
+impl BlockGraphExport {
+    /// Checks that the bounds of a block graph export are ordered.
+    ///
+    /// # Arguments
+    /// * `start_slot`: the slot the export starts from (included), if any
+    /// * `end_slot`: the slot the export ends at (excluded), if any
+    pub fn check_slot_range(
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> Result<(), ConsensusError> {
+        match (start_slot, end_slot) {
+            (Some(start), Some(end)) if start > end => Err(ConsensusError::InvalidSlotRange(
+                format!("start slot {} is after end slot {}", start, end),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockGraphExport;
+    use crate::error::ConsensusError;
+    use massa_models::slot::Slot;
+
+    #[test]
+    fn test_check_slot_range() {
+        let (low, high) = (Slot::new(1, 0), Slot::new(2, 0));
+        assert!(BlockGraphExport::check_slot_range(Some(low), Some(high)).is_ok());
+        assert!(BlockGraphExport::check_slot_range(Some(low), Some(low)).is_ok());
+        assert!(BlockGraphExport::check_slot_range(Some(high), None).is_ok());
+        assert!(BlockGraphExport::check_slot_range(None, Some(low)).is_ok());
+        assert!(matches!(
+            BlockGraphExport::check_slot_range(Some(high), Some(low)),
+            Err(ConsensusError::InvalidSlotRange(_))
+        ));
+    }
+}
@@ -9,6 +9,10 @@ use nom::{error::context, multi::length_count, sequence::tuple, IResult, Parser}
 use serde::{Deserialize, Serialize};
 use std::ops::Bound::Included;
 
+/// Version of the serialization format of `BootstrapableGraph`,
+/// to be bumped on every change of that format so that mismatching nodes fail bootstrap cleanly
+pub const BOOTSTRAPABLE_GRAPH_FORMAT_VERSION: u32 = 0;
+
 /// Bootstrap graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BootstrapableGraph {
@@ -19,6 +23,7 @@ pub struct BootstrapableGraph {
 /// Basic serializer for `BootstrapableGraph`
 #[derive(Default)]
 pub struct BootstrapableGraphSerializer {
+    version_serializer: U32VarIntSerializer,
     block_count_serializer: U32VarIntSerializer,
     export_active_block_serializer: ExportActiveBlockSerializer,
 }
@@ -27,6 +32,7 @@ impl BootstrapableGraphSerializer {
     /// Creates a `BootstrapableGraphSerializer`
     pub fn new() -> Self {
         Self {
+            version_serializer: U32VarIntSerializer::new(),
             block_count_serializer: U32VarIntSerializer::new(),
             export_active_block_serializer: ExportActiveBlockSerializer::new(),
         }
@@ -51,6 +57,10 @@ impl Serializer<BootstrapableGraph> for BootstrapableGraphSerializer {
         value: &BootstrapableGraph,
         buffer: &mut Vec<u8>,
     ) -> Result<(), SerializeError> {
+        // format version
+        self.version_serializer
+            .serialize(&BOOTSTRAPABLE_GRAPH_FORMAT_VERSION, buffer)?;
+
         // block count
         self.block_count_serializer.serialize(
             &value
@@ -73,6 +83,7 @@ impl Serializer<BootstrapableGraph> for BootstrapableGraphSerializer {
 
 /// Basic deserializer for `BootstrapableGraph`
 pub struct BootstrapableGraphDeserializer {
+    version_deserializer: U32VarIntDeserializer,
     block_count_deserializer: U32VarIntDeserializer,
     export_active_block_deserializer: ExportActiveBlockDeserializer,
 }
@@ -87,6 +98,10 @@ impl BootstrapableGraphDeserializer {
         max_operations_per_block: u32,
     ) -> Self {
         Self {
+            version_deserializer: U32VarIntDeserializer::new(
+                Included(BOOTSTRAPABLE_GRAPH_FORMAT_VERSION),
+                Included(BOOTSTRAPABLE_GRAPH_FORMAT_VERSION),
+            ),
             block_count_deserializer: U32VarIntDeserializer::new(
                 Included(0),
                 Included(max_bootstrap_blocks),
@@ -124,19 +139,63 @@ impl Deserializer<BootstrapableGraph> for BootstrapableGraphDeserializer {
     ) -> IResult<&'a [u8], BootstrapableGraph, E> {
         context(
             "Failed BootstrapableGraph deserialization",
-            tuple((context(
-                "Failed active_blocks deserialization",
-                length_count(
-                    context("Failed final block count deserialization", |input| {
-                        self.block_count_deserializer.deserialize(input)
-                    }),
-                    context("Failed export_active_block deserialization", |input| {
-                        self.export_active_block_deserializer.deserialize(input)
-                    }),
+            tuple((
+                context("Unsupported BootstrapableGraph format version", |input| {
+                    self.version_deserializer.deserialize(input)
+                }),
+                context(
+                    "Failed active_blocks deserialization",
+                    length_count(
+                        context("Failed final block count deserialization", |input| {
+                            self.block_count_deserializer.deserialize(input)
+                        }),
+                        context("Failed export_active_block deserialization", |input| {
+                            self.export_active_block_deserializer.deserialize(input)
+                        }),
+                    ),
                 ),
-            ),)),
+            )),
         )
-        .map(|(final_blocks,)| BootstrapableGraph { final_blocks })
+        .map(|(_version, final_blocks)| BootstrapableGraph { final_blocks })
         .parse(buffer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BootstrapableGraph, BootstrapableGraphDeserializer, BootstrapableGraphSerializer,
+        BOOTSTRAPABLE_GRAPH_FORMAT_VERSION,
+    };
+    use massa_serialization::{DeserializeError, Deserializer, Serializer, U32VarIntSerializer};
+
+    #[test]
+    fn test_unsupported_format_version() {
+        let graph = BootstrapableGraph {
+            final_blocks: Vec::new(),
+        };
+        let deserializer = BootstrapableGraphDeserializer::new(32, 16, 10, 10);
+
+        // a graph of the current version goes through
+        let mut buffer = Vec::new();
+        BootstrapableGraphSerializer::new()
+            .serialize(&graph, &mut buffer)
+            .unwrap();
+        assert!(deserializer
+            .deserialize::<DeserializeError>(&buffer)
+            .is_ok());
+
+        // a graph with a bumped version is rejected with a clear error
+        let mut bumped = Vec::new();
+        U32VarIntSerializer::new()
+            .serialize(&(BOOTSTRAPABLE_GRAPH_FORMAT_VERSION + 1), &mut bumped)
+            .unwrap();
+        bumped.extend_from_slice(&buffer[1..]);
+        let err = deserializer
+            .deserialize::<DeserializeError>(&bumped)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported BootstrapableGraph format version"));
+    }
+}
@@ -17,7 +17,7 @@ pub trait ConsensusController: Send + Sync {
     /// * `end_slot`: the slot to end the export at, if None, the export ends at the current slot
     ///
     /// # Returns
-    /// The export of the graph
+    /// The export of the graph, or `ConsensusError::InvalidSlotRange` if `start_slot` is after `end_slot`
     fn get_block_graph_status(
         &self,
         start_slot: Option<Slot>,
@@ -68,7 +68,9 @@ pub trait ConsensusController: Send + Sync {
     /// Get the best parents for the next block to be produced
     ///
     /// # Returns
-    /// The id of best parents for the next block to be produced along with their period
+    /// The id of best parents for the next block to be produced along with their period,
+    /// one entry per thread indexed by thread.
+    /// In each thread, parents with equal periods are tie-broken by the lowest `BlockId` bytes.
     fn get_best_parents(&self) -> Vec<(BlockId, u64)>;
 
     /// Get the block id of the block at a specific slot in the blockclique
@@ -80,6 +82,43 @@ pub trait ConsensusController: Send + Sync {
     /// The block id of the block at the specified slot if exists
     fn get_blockclique_block_at_slot(&self, slot: Slot) -> Option<BlockId>;
 
+    /// Get the block ids of the blocks at every slot of a range in the blockclique
+    ///
+    /// # Arguments
+    /// * `start_slot`: the first slot of the range (included)
+    /// * `end_slot`: the last slot of the range (excluded)
+    ///
+    /// # Returns
+    /// Each slot of the range, in increasing order, along with the block id at this slot if exists,
+    /// or `ConsensusError::InvalidSlotRange` if `start_slot` is after `end_slot`
+    /// or if the range spans more than `max_item_return_count` slots
+    fn get_blockclique_blocks_in_range(
+        &self,
+        start_slot: Slot,
+        end_slot: Slot,
+    ) -> Result<Vec<(Slot, Option<BlockId>)>, ConsensusError>;
+
+    /// Get the final blocks of the graph at or after a given slot
+    ///
+    /// # Arguments
+    /// * `slot`: the first slot to consider (included)
+    ///
+    /// # Returns
+    /// The final blocks with their slot, sorted by increasing slot, limited to the `max_item_return_count` earliest ones.
+    /// Final blocks that were already pruned from the graph are not returned.
+    fn get_finalized_blocks_since(&self, slot: Slot) -> Vec<(Slot, BlockId)>;
+
+    /// Get the ancestors of a block by following its parents in its own thread
+    ///
+    /// # Arguments
+    /// * `block_id`: the block to get the ancestors of
+    /// * `max_depth`: the maximum number of ancestors to return
+    ///
+    /// # Returns
+    /// The ancestors of the block with their slot, from the closest to the oldest.
+    /// The list stops early at genesis or at the first ancestor that is no longer in the graph.
+    fn get_block_ancestors(&self, block_id: BlockId, max_depth: usize) -> Vec<(BlockId, Slot)>;
+
     /// Get the latest block, that is in the blockclique, in the thread of the given slot and before this `slot`.
     ///
     /// # Arguments:
@@ -41,6 +41,8 @@ pub enum ConsensusError {
     TransactionError(String),
     /// Protocol error {0}
     ProtocolError(#[from] ProtocolError),
+    /// invalid slot range: {0}
+    InvalidSlotRange(String),
 }
 
 /// Internal error
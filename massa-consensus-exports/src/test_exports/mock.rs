@@ -63,6 +63,20 @@ pub enum MockConsensusControllerMessage {
         slot: Slot,
         response_tx: mpsc::Sender<Option<BlockId>>,
     },
+    GetBlockcliqueBlocksInRange {
+        start_slot: Slot,
+        end_slot: Slot,
+        response_tx: mpsc::Sender<Result<Vec<(Slot, Option<BlockId>)>, ConsensusError>>,
+    },
+    GetFinalizedBlocksSince {
+        slot: Slot,
+        response_tx: mpsc::Sender<Vec<(Slot, BlockId)>>,
+    },
+    GetBlockAncestors {
+        block_id: BlockId,
+        max_depth: usize,
+        response_tx: mpsc::Sender<Vec<(BlockId, Slot)>>,
+    },
     GetLatestBlockcliqueBlockAtSlot {
         slot: Slot,
         response_tx: mpsc::Sender<BlockId>,
@@ -126,6 +140,7 @@ impl ConsensusController for MockConsensusController {
         start_slot: Option<Slot>,
         end_slot: Option<Slot>,
     ) -> Result<BlockGraphExport, ConsensusError> {
+        BlockGraphExport::check_slot_range(start_slot, end_slot)?;
         let (response_tx, response_rx) = mpsc::channel();
         self.0
             .lock()
@@ -217,6 +232,50 @@ impl ConsensusController for MockConsensusController {
         response_rx.recv().unwrap()
     }
 
+    fn get_blockclique_blocks_in_range(
+        &self,
+        start_slot: Slot,
+        end_slot: Slot,
+    ) -> Result<Vec<(Slot, Option<BlockId>)>, ConsensusError> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .unwrap()
+            .send(
+                MockConsensusControllerMessage::GetBlockcliqueBlocksInRange {
+                    start_slot,
+                    end_slot,
+                    response_tx,
+                },
+            )
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
+    fn get_finalized_blocks_since(&self, slot: Slot) -> Vec<(Slot, BlockId)> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .unwrap()
+            .send(MockConsensusControllerMessage::GetFinalizedBlocksSince { slot, response_tx })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
+    fn get_block_ancestors(&self, block_id: BlockId, max_depth: usize) -> Vec<(BlockId, Slot)> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .unwrap()
+            .send(MockConsensusControllerMessage::GetBlockAncestors {
+                block_id,
+                max_depth,
+                response_tx,
+            })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
     fn get_latest_blockclique_block_at_slot(&self, slot: Slot) -> BlockId {
         let (response_tx, response_rx) = mpsc::channel();
         self.0
@@ -70,6 +70,7 @@ impl ConsensusController for ConsensusControllerImpl {
         start_slot: Option<Slot>,
         end_slot: Option<Slot>,
     ) -> Result<BlockGraphExport, ConsensusError> {
+        BlockGraphExport::check_slot_range(start_slot, end_slot)?;
         self.shared_state
             .read()
             .extract_block_graph_part(start_slot, end_slot)
@@ -213,6 +214,50 @@ impl ConsensusController for ConsensusControllerImpl {
             .get_blockclique_block_at_slot(&slot)
     }
 
+    /// Get the blocks, that are in the blockclique, at every slot of a range.
+    ///
+    /// # Arguments:
+    /// * `start_slot`: the first slot of the range (included)
+    /// * `end_slot`: the last slot of the range (excluded)
+    ///
+    /// # Returns:
+    /// Each slot of the range along with the block id at this slot if exists,
+    /// or `ConsensusError::InvalidSlotRange` if the range is reversed or too large
+    fn get_blockclique_blocks_in_range(
+        &self,
+        start_slot: Slot,
+        end_slot: Slot,
+    ) -> Result<Vec<(Slot, Option<BlockId>)>, ConsensusError> {
+        self.shared_state
+            .read()
+            .get_blockclique_blocks_in_range(&start_slot, &end_slot)
+    }
+
+    /// Get the final blocks at or after a given slot.
+    ///
+    /// # Arguments:
+    /// * `slot`: the first slot to consider (included)
+    ///
+    /// # Returns:
+    /// The final blocks with their slot, sorted by slot and limited to `max_item_return_count`
+    fn get_finalized_blocks_since(&self, slot: Slot) -> Vec<(Slot, BlockId)> {
+        self.shared_state.read().get_finalized_blocks_since(&slot)
+    }
+
+    /// Get the ancestors of a block in its own thread.
+    ///
+    /// # Arguments:
+    /// * `block_id`: the block to get the ancestors of
+    /// * `max_depth`: the maximum number of ancestors to return
+    ///
+    /// # Returns:
+    /// The ancestors of the block with their slot, from the closest to the oldest
+    fn get_block_ancestors(&self, block_id: BlockId, max_depth: usize) -> Vec<(BlockId, Slot)> {
+        self.shared_state
+            .read()
+            .get_block_ancestors(&block_id, max_depth)
+    }
+
     /// Get the latest block, that is in the blockclique, in the thread of the given slot and before this `slot`.
     ///
     /// # Arguments:
@@ -154,6 +154,80 @@ impl ConsensusState {
             })
     }
 
+    /// get the blockclique (or final) block IDs of all the slots in `[start_slot, end_slot)`,
+    /// the range being rejected if it is reversed or spans more than `max_item_return_count` slots
+    pub fn get_blockclique_blocks_in_range(
+        &self,
+        start_slot: &Slot,
+        end_slot: &Slot,
+    ) -> Result<Vec<(Slot, Option<BlockId>)>, ConsensusError> {
+        BlockGraphExport::check_slot_range(Some(*start_slot), Some(*end_slot))?;
+        let slot_count = end_slot
+            .slots_since(start_slot, self.config.thread_count)
+            .map_err(|err| ConsensusError::InvalidSlotRange(err.to_string()))?;
+        if slot_count > self.config.max_item_return_count as u64 {
+            return Err(ConsensusError::InvalidSlotRange(format!(
+                "range from {} to {} spans {} slots, more than the maximum of {}",
+                start_slot, end_slot, slot_count, self.config.max_item_return_count
+            )));
+        }
+        let mut result = Vec::with_capacity(slot_count as usize);
+        let mut slot = *start_slot;
+        while slot < *end_slot {
+            result.push((slot, self.get_blockclique_block_at_slot(&slot)));
+            slot = match slot.get_next_slot(self.config.thread_count) {
+                Ok(next_slot) => next_slot,
+                Err(_) => break,
+            };
+        }
+        Ok(result)
+    }
+
+    /// get the final active blocks at or after `slot`, sorted by slot and limited to `max_item_return_count`
+    pub fn get_finalized_blocks_since(&self, slot: &Slot) -> Vec<(Slot, BlockId)> {
+        select_finalized_blocks_since(
+            self.active_index.iter().filter_map(|block_id| {
+                match self.block_statuses.get(block_id) {
+                    Some(BlockStatus::Active { a_block, .. }) if a_block.is_final => {
+                        Some((a_block.slot, *block_id))
+                    }
+                    _ => None,
+                }
+            }),
+            slot,
+            self.config.max_item_return_count,
+        )
+    }
+
+    /// get the ancestors of an active block by following its parents in its own thread,
+    /// stopping at genesis, at the first ancestor that is not active anymore, or after `max_depth` ancestors
+    pub fn get_block_ancestors(
+        &self,
+        block_id: &BlockId,
+        max_depth: usize,
+    ) -> Vec<(BlockId, Slot)> {
+        let mut ancestors = Vec::new();
+        let mut current_id = *block_id;
+        while ancestors.len() < max_depth {
+            let a_block = match self.get_full_active_block(&current_id) {
+                Some((a_block, _)) => a_block,
+                None => break,
+            };
+            // genesis blocks have no parents
+            let parent_id = match a_block.parents.get(a_block.slot.thread as usize) {
+                Some((parent_id, _)) => *parent_id,
+                None => break,
+            };
+            let parent_slot = match self.get_full_active_block(&parent_id) {
+                Some((parent_block, _)) => parent_block.slot,
+                None => break,
+            };
+            ancestors.push((parent_id, parent_slot));
+            current_id = parent_id;
+        }
+        ancestors
+    }
+
     /// get the latest blockclique (or final) block ID at a given slot, if any
     pub fn get_latest_blockclique_block_at_slot(&self, slot: &Slot) -> BlockId {
         let (mut best_block_id, mut best_block_period) = self
@@ -521,3 +595,60 @@ impl ConsensusState {
         Ok(result)
     }
 }
+
+/// Selects the final blocks at or after `slot` among `final_blocks`,
+/// sorted by slot (then by id) and limited to the `max_count` earliest ones.
+pub(crate) fn select_finalized_blocks_since(
+    final_blocks: impl IntoIterator<Item = (Slot, BlockId)>,
+    slot: &Slot,
+    max_count: usize,
+) -> Vec<(Slot, BlockId)> {
+    let mut result: Vec<(Slot, BlockId)> = final_blocks
+        .into_iter()
+        .filter(|(block_slot, _)| block_slot >= slot)
+        .collect();
+    result.sort_unstable();
+    result.truncate(max_count);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_finalized_blocks_since;
+    use massa_hash::Hash;
+    use massa_models::{block_id::BlockId, slot::Slot};
+
+    fn block_id(seed: &str) -> BlockId {
+        BlockId(Hash::compute_from(seed.as_bytes()))
+    }
+
+    #[test]
+    fn test_finalized_blocks_since() {
+        let (a, b, c) = (block_id("a"), block_id("b"), block_id("c"));
+        let final_blocks = vec![
+            (Slot::new(3, 0), c),
+            (Slot::new(1, 0), a),
+            (Slot::new(2, 1), b),
+        ];
+
+        // the cursor slot is included and the result is sorted by slot
+        assert_eq!(
+            select_finalized_blocks_since(final_blocks.clone(), &Slot::new(2, 1), 10),
+            vec![(Slot::new(2, 1), b), (Slot::new(3, 0), c)]
+        );
+        // only the earliest blocks are kept
+        assert_eq!(
+            select_finalized_blocks_since(final_blocks.clone(), &Slot::new(0, 0), 2),
+            vec![(Slot::new(1, 0), a), (Slot::new(2, 1), b)]
+        );
+        // resuming from the slot following the last returned one yields the rest
+        let next_slot = Slot::new(2, 1).get_next_slot(2).unwrap();
+        assert_eq!(
+            select_finalized_blocks_since(final_blocks.clone(), &next_slot, 2),
+            vec![(Slot::new(3, 0), c)]
+        );
+        // nothing was finalized after the cursor
+        assert!(select_finalized_blocks_since(final_blocks, &Slot::new(3, 1), 10).is_empty());
+        assert!(select_finalized_blocks_since(Vec::new(), &Slot::new(0, 0), 10).is_empty());
+    }
+}
@@ -602,19 +602,15 @@ impl ConsensusState {
         {
             let blockclique = &self.max_cliques[position_blockclique];
 
-            // init best parents as latest_final_blocks_periods
-            self.best_parents = self.latest_final_blocks_periods.clone();
-            // for each blockclique block, set it as best_parent in its own thread
-            // if its period is higher than the current best_parent in that thread
+            let mut candidates = Vec::with_capacity(blockclique.block_ids.len());
             for block_h in blockclique.block_ids.iter() {
                 let b_slot = match self.block_statuses.get(block_h) {
                     Some(BlockStatus::Active { a_block, storage: _ }) => a_block.slot,
                     _ => return Err(ConsensusError::ContainerInconsistency(format!("inconsistency inside block statuses updating best parents while adding {} - missing {}", add_block_id, block_h))),
                 };
-                if b_slot.period > self.best_parents[b_slot.thread as usize].1 {
-                    self.best_parents[b_slot.thread as usize] = (*block_h, b_slot.period);
-                }
+                candidates.push((*block_h, b_slot));
             }
+            self.best_parents = select_best_parents(&self.latest_final_blocks_periods, candidates);
         }
 
         // list stale blocks
@@ -873,3 +869,57 @@ impl ConsensusState {
         Ok(())
     }
 }
+
+/// Select the best parent of each thread among the latest final blocks and the given candidates.
+///
+/// The result contains one `(block_id, period)` entry per thread, indexed by thread.
+/// In each thread, the candidate with the highest period is kept,
+/// and candidates with equal periods are tie-broken by the lowest `BlockId` bytes
+/// so that the result does not depend on the iteration order of `candidates`.
+pub(crate) fn select_best_parents(
+    latest_final_blocks_periods: &[(BlockId, u64)],
+    candidates: impl IntoIterator<Item = (BlockId, Slot)>,
+) -> Vec<(BlockId, u64)> {
+    let mut best_parents = latest_final_blocks_periods.to_vec();
+    for (block_id, slot) in candidates {
+        let (best_id, best_period) = &mut best_parents[slot.thread as usize];
+        if slot.period > *best_period || (slot.period == *best_period && block_id < *best_id) {
+            *best_id = block_id;
+            *best_period = slot.period;
+        }
+    }
+    best_parents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_best_parents;
+    use massa_hash::Hash;
+    use massa_models::{block_id::BlockId, slot::Slot};
+
+    fn block_id(seed: &str) -> BlockId {
+        BlockId(Hash::compute_from(seed.as_bytes()))
+    }
+
+    #[test]
+    fn test_best_parents_tie_break() {
+        let genesis = vec![(block_id("genesis_0"), 0), (block_id("genesis_1"), 0)];
+        let (a, b) = (block_id("a"), block_id("b"));
+        let expected_id = std::cmp::min(a, b);
+
+        let forward =
+            select_best_parents(&genesis, vec![(a, Slot::new(3, 0)), (b, Slot::new(3, 0))]);
+        let backward =
+            select_best_parents(&genesis, vec![(b, Slot::new(3, 0)), (a, Slot::new(3, 0))]);
+        assert_eq!(forward, vec![(expected_id, 3), genesis[1]]);
+        assert_eq!(forward, backward);
+
+        // a higher period still wins over a lower block id
+        let higher = std::cmp::max(a, b);
+        let res = select_best_parents(
+            &genesis,
+            vec![(expected_id, Slot::new(3, 1)), (higher, Slot::new(4, 1))],
+        );
+        assert_eq!(res, vec![genesis[0], (higher, 4)]);
+    }
+}
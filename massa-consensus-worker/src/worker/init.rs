@@ -2,13 +2,13 @@ use massa_consensus_exports::{
     block_status::BlockStatus, bootstrapable_graph::BootstrapableGraph, error::ConsensusError,
     ConsensusConfig,
 };
-use massa_hash::Hash;
 use massa_models::{
     active_block::ActiveBlock,
     address::Address,
     block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer},
     block_id::BlockId,
+    operation::compute_operations_merkle_root,
     prehash::PreHashMap,
     secure_share::SecureShareContent,
     slot::Slot,
@@ -44,7 +44,7 @@ pub fn create_genesis_block(
         BlockHeader {
             slot: Slot::new(0, thread_number),
             parents: Vec::new(),
-            operation_merkle_root: Hash::compute_from(&Vec::new()),
+            operation_merkle_root: compute_operations_merkle_root(&[]),
             endorsements: Vec::new(),
         },
         BlockHeaderSerializer::new(),
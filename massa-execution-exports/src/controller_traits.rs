@@ -4,7 +4,7 @@
 
 use crate::types::ReadOnlyExecutionRequest;
 use crate::ExecutionError;
-use crate::{ExecutionAddressInfo, ReadOnlyExecutionOutput};
+use crate::{ExecutionAddressInfo, ModuleCacheStats, ReadOnlyExecutionOutput};
 use massa_models::address::Address;
 use massa_models::amount::Amount;
 use massa_models::block_id::BlockId;
@@ -93,6 +93,13 @@ pub trait ExecutionController: Send + Sync {
     /// Get execution statistics
     fn get_stats(&self) -> ExecutionStats;
 
+    /// Gets the total gas consumed by the operations and asynchronous messages executed at a slot.
+    /// Returns None if the slot was not executed or is no longer tracked.
+    fn get_slot_gas_usage(&self, slot: Slot) -> Option<u64>;
+
+    /// Gets the hit, miss and eviction counters of the compiled module cache
+    fn get_module_cache_stats(&self) -> ModuleCacheStats;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn ExecutionController>`.
     fn clone_box(&self) -> Box<dyn ExecutionController>;
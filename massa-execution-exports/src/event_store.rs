@@ -3,8 +3,10 @@
 //! This module represents an event store allowing to store, search and retrieve
 //! a config-limited number of execution-generated events
 
+use massa_models::address::Address;
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
+use massa_models::slot::Slot;
 use std::collections::VecDeque;
 
 /// Store for events emitted by smart contracts
@@ -97,6 +99,25 @@ impl EventStore {
             .cloned()
             .collect()
     }
+
+    /// Get the events emitted by an address in the slot range `[start, end)`.
+    /// The emitter of an event is the address at the top of its call stack.
+    pub fn get_events_by_emitter(
+        &self,
+        emitter_address: Address,
+        start: Slot,
+        end: Slot,
+    ) -> VecDeque<SCOutputEvent> {
+        self.0
+            .iter()
+            .filter(|x| {
+                x.context.slot >= start
+                    && x.context.slot < end
+                    && x.context.call_stack.back() == Some(&emitter_address)
+            })
+            .cloned()
+            .collect()
+    }
 }
 
 #[test]
@@ -127,3 +148,61 @@ fn test_prune() {
     assert_eq!(store.0[1].data, "8");
     assert_eq!(store.0[0].data, "7");
 }
+
+#[test]
+fn test_get_events_by_emitter() {
+    use massa_hash::Hash;
+    use massa_models::output_event::EventExecutionContext;
+
+    let emitter = Address(Hash::compute_from(b"emitter"));
+    let other = Address(Hash::compute_from(b"other"));
+    let mut store = EventStore(VecDeque::new());
+    for i in 0..10 {
+        store.push(SCOutputEvent {
+            context: EventExecutionContext {
+                slot: Slot::new(i, 0),
+                block: None,
+                read_only: false,
+                index_in_slot: 0,
+                call_stack: VecDeque::from([if i % 2 == 0 { emitter } else { other }]),
+                origin_operation_id: None,
+                is_final: false,
+                is_error: false,
+            },
+            data: i.to_string(),
+        });
+    }
+    let events = store.get_events_by_emitter(emitter, Slot::new(2, 0), Slot::new(8, 0));
+    let data: Vec<_> = events.iter().map(|event| event.data.as_str()).collect();
+    assert_eq!(data, vec!["2", "4", "6"]);
+}
+
+#[test]
+fn test_get_events_by_emitter_nested_call() {
+    use massa_hash::Hash;
+    use massa_models::output_event::EventExecutionContext;
+
+    // `caller` calls `callee`, which emits the event
+    let caller = Address(Hash::compute_from(b"caller"));
+    let callee = Address(Hash::compute_from(b"callee"));
+    let mut store = EventStore(VecDeque::new());
+    store.push(SCOutputEvent {
+        context: EventExecutionContext {
+            slot: Slot::new(1, 0),
+            block: None,
+            read_only: false,
+            index_in_slot: 0,
+            call_stack: VecDeque::from([caller, callee]),
+            origin_operation_id: None,
+            is_final: false,
+            is_error: false,
+        },
+        data: "nested".to_string(),
+    });
+    let events = store.get_events_by_emitter(callee, Slot::new(0, 0), Slot::new(2, 0));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].data, "nested");
+    assert!(store
+        .get_events_by_emitter(caller, Slot::new(0, 0), Slot::new(2, 0))
+        .is_empty());
+}
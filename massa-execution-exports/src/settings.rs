@@ -2,7 +2,7 @@
 
 //! This module provides the structures used to provide configuration parameters to the Execution system
 
-use massa_models::amount::Amount;
+use massa_models::{address::Address, amount::Amount, prehash::PreHashSet};
 use massa_sc_runtime::GasCosts;
 use massa_time::MassaTime;
 use num::rational::Ratio;
@@ -57,12 +57,30 @@ pub struct ExecutionConfig {
     pub max_bytecode_size: u64,
     /// Max datastore value size
     pub max_datastore_value_size: u64,
+    /// Max number of addresses owned by a single call stack frame
+    pub max_owned_addresses: u64,
+    /// Max number of events emitted by smart contracts during the execution of a slot
+    pub max_events_per_slot: u64,
+    /// Max total data size of the events emitted by smart contracts during the execution of a slot
+    pub max_event_data_size_per_slot: u64,
+    /// Max total size in bytes of the datastore keys and values of a single address
+    pub max_datastore_size_per_address: u64,
     /// Max number of compiled modules in the cache
     pub max_module_cache_size: u32,
+    /// Max gas that can be spent compiling a module, independently of the gas available for its execution
+    pub max_compile_gas: u64,
+    /// Max depth of the call stack, bounding recursion independently of gas
+    pub max_call_depth: u64,
     /// Storage cost constants
     pub storage_costs_constants: StorageCostsConstants,
     /// Max gas for read only executions
     pub max_read_only_gas: u64,
+    /// Whether to emit an event every time the roll count of an address changes
+    pub emit_roll_change_events: bool,
     /// Gas costs
     pub gas_costs: GasCosts,
+    /// Contract addresses that cannot be called.
+    /// This is an emergency measure: all the nodes of the network must use the same list,
+    /// otherwise their executions diverge.
+    pub denied_contract_addresses: PreHashSet<Address>,
 }
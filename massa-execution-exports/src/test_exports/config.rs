@@ -40,8 +40,14 @@ impl Default for ExecutionConfig {
             max_datastore_key_length: MAX_DATASTORE_KEY_LENGTH,
             max_bytecode_size: MAX_BYTECODE_LENGTH,
             max_datastore_value_size: MAX_DATASTORE_VALUE_LENGTH,
+            max_owned_addresses: MAX_OWNED_ADDRESSES_PER_CALL,
+            max_events_per_slot: MAX_EVENTS_PER_SLOT,
+            max_event_data_size_per_slot: MAX_EVENT_DATA_SIZE_PER_SLOT,
+            max_datastore_size_per_address: MAX_DATASTORE_SIZE_PER_ADDRESS,
             storage_costs_constants,
             max_read_only_gas: 100_000_000,
+            emit_roll_change_events: false,
+            denied_contract_addresses: Default::default(),
             gas_costs: GasCosts::new(
                 concat!(
                     env!("CARGO_MANIFEST_DIR"),
@@ -56,6 +62,8 @@ impl Default for ExecutionConfig {
             )
             .unwrap(),
             max_module_cache_size: 1000,
+            max_compile_gas: MAX_COMPILE_GAS,
+            max_call_depth: MAX_CALL_DEPTH,
         }
     }
 }
@@ -3,8 +3,8 @@
 //! This file defines utilities to mock the crate for testing purposes
 
 use crate::{
-    ExecutionAddressInfo, ExecutionController, ExecutionError, ReadOnlyExecutionOutput,
-    ReadOnlyExecutionRequest,
+    ExecutionAddressInfo, ExecutionController, ExecutionError, ModuleCacheStats,
+    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
 };
 use massa_ledger_exports::LedgerEntry;
 use massa_models::{
@@ -121,6 +121,7 @@ impl ExecutionController for MockExecutionController {
             final_block_count: 0,
             final_executed_operations_count: 0,
             active_cursor: Slot::new(0, 0),
+            context_lock_hold_time: MassaTime::from_millis(0),
         }
     }
 
@@ -185,6 +186,14 @@ impl ExecutionController for MockExecutionController {
         BTreeMap::default()
     }
 
+    fn get_slot_gas_usage(&self, _slot: Slot) -> Option<u64> {
+        None
+    }
+
+    fn get_module_cache_stats(&self) -> ModuleCacheStats {
+        ModuleCacheStats::default()
+    }
+
     fn execute_readonly_request(
         &self,
         req: ReadOnlyExecutionRequest,
@@ -47,6 +47,8 @@ pub struct ExecutionOutput {
     pub state_changes: StateChanges,
     /// events emitted by the execution step
     pub events: EventStore,
+    /// total gas consumed by the operations and asynchronous messages executed at that slot
+    pub gas_used: u64,
 }
 
 /// structure describing the output of a read only execution
@@ -73,6 +75,8 @@ pub struct ReadOnlyExecutionRequest {
     ///
     /// Whether to start execution from final or active state
     pub is_final: bool,
+    /// Whether the execution is a view call, in which ledger writes and coin transfers are rejected
+    pub view_only: bool,
 }
 
 /// structure describing different possible targets of a read-only execution request
@@ -131,7 +135,26 @@ pub struct ExecutionStackElement {
     /// which is important for security.
     /// Note that we use a vector instead of a pre-hashed set to ensure order determinism,
     /// the performance hit of linear search remains minimal because `owned_addresses` will always contain very few elements.
+    /// Addresses are only ever appended to this list, so that it is in insertion order:
+    /// the address of the call itself first, then the addresses created during the call in creation order.
+    /// This ordering is exposed to smart contracts and is therefore consensus-critical.
     pub owned_addresses: Vec<Address>,
     /// Datastore (key value store) for `ExecuteSC` Operation
     pub operation_datastore: Option<Datastore>,
+    /// True if the call is read-only: it has no write access, even on its owned addresses.
+    /// Calls made from a read-only call are read-only as well.
+    pub is_read_only: bool,
+}
+
+/// Statistics of the compiled module cache
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleCacheStats {
+    /// number of module requests served from the cache
+    pub hits: u64,
+    /// number of module requests that required a compilation
+    pub misses: u64,
+    /// number of modules evicted from the cache to make room for new ones
+    pub evictions: u64,
+    /// number of modules currently held in the cache
+    pub cached_modules: usize,
 }
@@ -95,6 +95,15 @@ pub struct ExecutionContext {
     /// max gas for this execution
     pub max_gas: u64,
 
+    /// gas charged by the interface during this execution on top of the gas metered by the VM
+    /// (datastore size computations), against `max_gas`.
+    /// It is not restored on snapshot reset, as the gas was consumed anyway.
+    pub interface_gas_used: u64,
+
+    /// total size (keys and values) of the datastores computed so far, by address.
+    /// Cleared on snapshot reset, as the datastores may have changed.
+    pub datastore_sizes: BTreeMap<Address, u64>,
+
     /// slot at which the execution happens
     pub slot: Slot,
 
@@ -107,6 +116,9 @@ pub struct ExecutionContext {
     /// counter of newly created messages so far during this execution
     pub created_message_index: u64,
 
+    /// value of `contract_event_count` when the current operation or asynchronous message started executing
+    pub execution_start_contract_event_count: u64,
+
     /// block ID, if one is present at the execution slot
     pub opt_block_id: Option<BlockId>,
 
@@ -116,9 +128,26 @@ pub struct ExecutionContext {
     /// True if it's a read-only context
     pub read_only: bool,
 
+    /// True if the context only allows reading the ledger, for view calls:
+    /// no address is writable, so ledger writes, coin transfers and address creations are rejected
+    pub view_only: bool,
+
     /// generated events during this execution, with multiple indexes
     pub events: EventStore,
 
+    /// number of events emitted by smart contracts during this execution,
+    /// excluding the error events and the events generated by the node itself.
+    /// Like `events`, it is not restored on snapshot reset.
+    pub contract_event_count: u64,
+
+    /// total data size of the events emitted by smart contracts during this execution.
+    /// Like `events`, it is not restored on snapshot reset.
+    pub contract_events_data_size: u64,
+
+    /// total gas consumed by the VM during this slot execution.
+    /// Like `events`, it is not restored on snapshot reset.
+    pub gas_used: u64,
+
     /// Unsafe random state (can be predicted and manipulated)
     pub unsafe_rng: Xoshiro256PlusPlus,
 
@@ -130,6 +159,10 @@ pub struct ExecutionContext {
 
     // cache of compiled runtime modules
     pub module_cache: Arc<RwLock<ModuleCache>>,
+
+    /// cumulative time spent holding the context lock from the ABIs during this execution
+    #[cfg(feature = "context_lock_metrics")]
+    pub context_lock_hold_time: std::time::Duration,
 }
 
 impl ExecutionContext {
@@ -168,19 +201,28 @@ impl ExecutionContext {
             ),
             speculative_executed_ops: SpeculativeExecutedOps::new(final_state, active_history),
             max_gas: Default::default(),
+            interface_gas_used: Default::default(),
+            datastore_sizes: Default::default(),
             slot: Slot::new(0, 0),
             created_addr_index: Default::default(),
             created_event_index: Default::default(),
             created_message_index: Default::default(),
+            execution_start_contract_event_count: Default::default(),
             opt_block_id: Default::default(),
             stack: Default::default(),
             read_only: Default::default(),
+            view_only: Default::default(),
             events: Default::default(),
+            contract_event_count: Default::default(),
+            contract_events_data_size: Default::default(),
+            gas_used: Default::default(),
             unsafe_rng: Xoshiro256PlusPlus::from_seed([0u8; 32]),
             creator_address: Default::default(),
             origin_operation_id: Default::default(),
             module_cache,
             config,
+            #[cfg(feature = "context_lock_metrics")]
+            context_lock_hold_time: Default::default(),
         }
     }
 
@@ -221,6 +263,7 @@ impl ExecutionContext {
         self.created_event_index = snapshot.created_event_index;
         self.stack = snapshot.stack;
         self.unsafe_rng = snapshot.unsafe_rng;
+        self.datastore_sizes.clear();
 
         // For events, set snapshot delta to error events.
         // Start iterating from snapshot events length because we are dealing with a VecDeque.
@@ -282,6 +325,18 @@ impl ExecutionContext {
         }
     }
 
+    /// Returns a copy of the state of the unsafe RNG, to check that executions are deterministic
+    #[cfg(any(test, feature = "testing"))]
+    pub fn get_unsafe_rng_state(&self) -> Xoshiro256PlusPlus {
+        self.unsafe_rng.clone()
+    }
+
+    /// Restores a state of the unsafe RNG returned by `get_unsafe_rng_state`
+    #[cfg(any(test, feature = "testing"))]
+    pub fn set_unsafe_rng_state(&mut self, state: Xoshiro256PlusPlus) {
+        self.unsafe_rng = state;
+    }
+
     /// This function takes a batch of asynchronous operations to execute, removing them from the speculative pool.
     ///
     /// # Arguments
@@ -354,8 +409,8 @@ impl ExecutionContext {
         }
     }
 
-    /// Gets the current list of owned addresses (top of the stack)
-    /// Ordering is conserved for determinism
+    /// Gets the current list of owned addresses (top of the stack),
+    /// in insertion order (see `ExecutionStackElement::owned_addresses`) for determinism
     pub fn get_current_owned_addresses(&self) -> Result<Vec<Address>, ExecutionError> {
         match self.stack.last() {
             Some(v) => Ok(v.owned_addresses.clone()),
@@ -380,11 +435,20 @@ impl ExecutionContext {
         self.stack.iter().map(|v| v.address).collect()
     }
 
-    /// Checks whether the context currently grants write access to a given address
+    /// Checks whether writes are forbidden to the current call,
+    /// either because the context is view-only or because the current call (top of the stack) is read-only
+    pub fn is_write_forbidden(&self) -> bool {
+        self.view_only || self.stack.last().map_or(false, |v| v.is_read_only)
+    }
+
+    /// Checks whether the context currently grants write access to a given address.
+    /// A view-only context or a read-only call never grants write access.
     pub fn has_write_rights_on(&self, addr: &Address) -> bool {
-        self.stack
-            .last()
-            .map_or(false, |v| v.owned_addresses.contains(addr))
+        !self.is_write_forbidden()
+            && self
+                .stack
+                .last()
+                .map_or(false, |v| v.owned_addresses.contains(addr))
     }
 
     /// Creates a new smart contract address with initial bytecode, and returns this address
@@ -404,7 +468,10 @@ impl ExecutionContext {
         // add the index of the created address within this context to the seed
         data.append(&mut self.created_addr_index.to_be_bytes().to_vec());
         // add a flag on whether we are in read-only mode or not to the seed
-        // this prevents read-only contexts from shadowing existing addresses
+        // this prevents read-only contexts from shadowing existing addresses.
+        // The flag only changes the seed: the thread of the address is derived from its hash
+        // (see `Address::get_thread`) like for any other address, so read-only and writable addresses
+        // created at the same slot and index are distinct and spread over threads in the same way.
         if self.read_only {
             data.push(0u8);
         } else {
@@ -413,6 +480,23 @@ impl ExecutionContext {
         // hash the seed to get a unique address
         let address = Address(massa_hash::Hash::compute_from(&data));
 
+        if self.is_write_forbidden() {
+            return Err(ExecutionError::RuntimeError(
+                "could not create SC address: not allowed in a view-only context or read-only call"
+                    .into(),
+            ));
+        }
+
+        // bound the number of addresses owned by the current call
+        if let Some(v) = self.stack.last() {
+            if v.owned_addresses.len() as u64 >= self.config.max_owned_addresses {
+                return Err(ExecutionError::RuntimeError(format!(
+                    "could not create SC address: max owned addresses per call ({}) reached",
+                    self.config.max_owned_addresses
+                )));
+            }
+        }
+
         // add this address with its bytecode to the speculative ledger
         self.speculative_ledger.create_new_sc_address(
             self.get_current_address()?,
@@ -424,6 +508,7 @@ impl ExecutionContext {
         // so that the current call has write access to it
         // from now and for its whole duration,
         // in order to allow initializing newly created ledger entries.
+        // The address is appended to keep the list in insertion order, which is consensus-critical.
         match self.stack.last_mut() {
             Some(v) => {
                 v.owned_addresses.push(address);
@@ -488,9 +573,92 @@ impl ExecutionContext {
             )));
         }
 
+        // check the datastore size quota of the address
+        let prev_value_len = self
+            .speculative_ledger
+            .get_data_entry(address, &key)
+            .map(|value| value.len());
+        let datastore_size =
+            self.check_datastore_size(address, &key, prev_value_len, data.len())?;
+
         // set data entry
         self.speculative_ledger
-            .set_data_entry(&self.get_current_address()?, address, key, data)
+            .set_data_entry(&self.get_current_address()?, address, key, data)?;
+        self.datastore_sizes.insert(*address, datastore_size);
+        Ok(())
+    }
+
+    /// Checks that writing a datastore entry keeps the total size of the datastore of an address
+    /// within `max_datastore_size_per_address`.
+    ///
+    /// # Arguments
+    /// * address: the address of the ledger entry
+    /// * key: the datastore key that is about to be written
+    /// * prev_value_len: the length of the value currently stored at `key`, None if there is none
+    /// * value_len: the length of the value that is about to be written
+    ///
+    /// # Returns
+    /// The size of the datastore once the entry is written
+    fn check_datastore_size(
+        &mut self,
+        address: &Address,
+        key: &[u8],
+        prev_value_len: Option<usize>,
+        value_len: usize,
+    ) -> Result<u64, ExecutionError> {
+        let mut datastore_size = self.get_datastore_size(address)?;
+        if let Some(prev_value_len) = prev_value_len {
+            datastore_size = datastore_size.saturating_sub((key.len() + prev_value_len) as u64);
+        }
+        datastore_size = datastore_size.saturating_add((key.len() + value_len) as u64);
+        if datastore_size > self.config.max_datastore_size_per_address {
+            return Err(ExecutionError::RuntimeError(format!(
+                "writing in the datastore of address {} failed: its size would be {} bytes, exceeding the quota of {} bytes",
+                address, datastore_size, self.config.max_datastore_size_per_address
+            )));
+        }
+        Ok(datastore_size)
+    }
+
+    /// Gets the total size (keys and values) of the datastore of an address.
+    /// The first time an address is queried, its size is computed by reading every entry of its datastore,
+    /// which is charged one gas unit per byte read. It is then kept up to date by the datastore writes.
+    ///
+    /// # Arguments
+    /// * address: the address of the ledger entry
+    fn get_datastore_size(&mut self, address: &Address) -> Result<u64, ExecutionError> {
+        if let Some(datastore_size) = self.datastore_sizes.get(address) {
+            return Ok(*datastore_size);
+        }
+        let mut datastore_size: u64 = 0;
+        for key in self.speculative_ledger.get_keys(address).unwrap_or_default() {
+            let value_len = self
+                .speculative_ledger
+                .get_data_entry(address, &key)
+                .map_or(0, |value| value.len());
+            datastore_size = datastore_size.saturating_add((key.len() + value_len) as u64);
+        }
+        self.charge_gas(datastore_size)?;
+        self.datastore_sizes.insert(*address, datastore_size);
+        Ok(datastore_size)
+    }
+
+    /// Charges gas to the current execution on top of the gas metered by the VM.
+    /// Fails if the max gas of the execution would be exceeded.
+    ///
+    /// # Arguments
+    /// * gas: the amount of gas to charge
+    pub fn charge_gas(&mut self, gas: u64) -> Result<(), ExecutionError> {
+        let interface_gas_used = self.interface_gas_used.saturating_add(gas);
+        if interface_gas_used > self.max_gas {
+            return Err(ExecutionError::RuntimeError(format!(
+                "not enough gas: {} gas charged by the interface, exceeding the max gas of {}",
+                interface_gas_used, self.max_gas
+            )));
+        }
+        self.interface_gas_used = interface_gas_used;
+        self.gas_used = self.gas_used.saturating_add(gas);
+        Ok(())
     }
 
     /// Appends data to a datastore entry for an address in the speculative ledger.
@@ -527,11 +695,18 @@ impl ExecutionContext {
             })?;
 
         // append data
+        let prev_value_len = res_data.len();
         res_data.extend(data);
 
+        // check the datastore size quota of the address
+        let datastore_size =
+            self.check_datastore_size(address, &key, Some(prev_value_len), res_data.len())?;
+
         // set data entry
         self.speculative_ledger
-            .set_data_entry(&self.get_current_address()?, address, key, res_data)
+            .set_data_entry(&self.get_current_address()?, address, key, res_data)?;
+        self.datastore_sizes.insert(*address, datastore_size);
+        Ok(())
     }
 
     /// Deletes a datastore entry for an address.
@@ -553,9 +728,24 @@ impl ExecutionContext {
             )));
         }
 
+        // get the length of the deleted value to keep the datastore size up to date
+        let value_len = if self.datastore_sizes.contains_key(address) {
+            self.speculative_ledger
+                .get_data_entry(address, key)
+                .map(|value| value.len())
+        } else {
+            None
+        };
+
         // delete entry
         self.speculative_ledger
-            .delete_data_entry(&self.get_current_address()?, address, key)
+            .delete_data_entry(&self.get_current_address()?, address, key)?;
+        if let (Some(value_len), Some(datastore_size)) =
+            (value_len, self.datastore_sizes.get_mut(address))
+        {
+            *datastore_size = datastore_size.saturating_sub((key.len() + value_len) as u64);
+        }
+        Ok(())
     }
 
     /// Transfers coins from one address to another.
@@ -728,11 +918,15 @@ impl ExecutionContext {
             pos_changes: self.speculative_roll_state.take(),
             executed_ops_changes: self.speculative_executed_ops.take(),
         };
+        self.contract_event_count = 0;
+        self.contract_events_data_size = 0;
+        self.execution_start_contract_event_count = 0;
         ExecutionOutput {
             slot,
             block_id: std::mem::take(&mut self.opt_block_id),
             state_changes,
             events: std::mem::take(&mut self.events),
+            gas_used: std::mem::take(&mut self.gas_used),
         }
     }
 
@@ -803,6 +997,31 @@ impl ExecutionContext {
         self.events.push(event);
     }
 
+    /// Emits a previously created event on behalf of a smart contract (see `event_emit`),
+    /// failing if the slot limits on the event count or the total event data size would be exceeded.
+    /// Only the events emitted by smart contracts count towards those limits.
+    pub fn try_event_emit(&mut self, event: SCOutputEvent) -> Result<(), ExecutionError> {
+        if self.contract_event_count >= self.config.max_events_per_slot {
+            return Err(ExecutionError::RuntimeError(format!(
+                "maximum number of events per slot ({}) reached",
+                self.config.max_events_per_slot
+            )));
+        }
+        let contract_events_data_size = self
+            .contract_events_data_size
+            .saturating_add(event.data.len() as u64);
+        if contract_events_data_size > self.config.max_event_data_size_per_slot {
+            return Err(ExecutionError::RuntimeError(format!(
+                "maximum event data size per slot ({} bytes) exceeded",
+                self.config.max_event_data_size_per_slot
+            )));
+        }
+        self.event_emit(event);
+        self.contract_event_count += 1;
+        self.contract_events_data_size = contract_events_data_size;
+        Ok(())
+    }
+
     /// Check if an operation was previously executed (to prevent reuse)
     pub fn is_op_executed(&self, op_id: &OperationId) -> bool {
         self.speculative_executed_ops.is_op_executed(op_id)
@@ -7,7 +7,7 @@ use crate::execution::ExecutionState;
 use crate::request_queue::{RequestQueue, RequestWithResponseSender};
 use massa_execution_exports::{
     ExecutionAddressInfo, ExecutionConfig, ExecutionController, ExecutionError, ExecutionManager,
-    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
+    ModuleCacheStats, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
 };
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
@@ -256,6 +256,16 @@ impl ExecutionController for ExecutionControllerImpl {
         self.execution_state.read().get_stats()
     }
 
+    /// Gets the total gas consumed by the operations and asynchronous messages executed at a slot
+    fn get_slot_gas_usage(&self, slot: Slot) -> Option<u64> {
+        self.execution_state.read().get_slot_gas_usage(&slot)
+    }
+
+    /// Gets the hit, miss and eviction counters of the compiled module cache
+    fn get_module_cache_stats(&self) -> ModuleCacheStats {
+        self.execution_state.read().get_module_cache_stats()
+    }
+
     /// Returns a boxed clone of self.
     /// Allows cloning `Box<dyn ExecutionController>`,
     /// see `massa-execution-exports/controller_traits.rs`
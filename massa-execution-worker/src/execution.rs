@@ -16,13 +16,13 @@ use crate::stats::ExecutionStatsCounter;
 use massa_async_pool::AsyncMessage;
 use massa_execution_exports::{
     EventStore, ExecutionConfig, ExecutionError, ExecutionOutput, ExecutionStackElement,
-    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+    ModuleCacheStats, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
 };
 use massa_final_state::FinalState;
 use massa_ledger_exports::{SetOrDelete, SetUpdateOrDelete};
 use massa_models::address::ExecutionAddressCycleInfo;
 use massa_models::execution::EventFilter;
-use massa_models::output_event::SCOutputEvent;
+use massa_models::output_event::{EventExecutionContext, SCOutputEvent};
 use massa_models::prehash::PreHashSet;
 use massa_models::stats::ExecutionStats;
 use massa_models::{
@@ -35,7 +35,7 @@ use massa_pos_exports::SelectorController;
 use massa_sc_runtime::{Interface, Response, RuntimeModule};
 use massa_storage::Storage;
 use parking_lot::{Mutex, RwLock};
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
@@ -63,6 +63,8 @@ pub(crate) struct ExecutionState {
     pub final_cursor: Slot,
     // store containing execution events that became final
     final_events: EventStore,
+    // gas used by the latest final slots, one per thread, oldest at the front
+    final_gas_usage: VecDeque<(Slot, u64)>,
     // final state with atomic R/W access
     final_state: Arc<RwLock<FinalState>>,
     // execution context (see documentation in context.rs)
@@ -96,6 +98,7 @@ impl ExecutionState {
         let module_cache = Arc::new(RwLock::new(ModuleCache::new(
             config.gas_costs.clone(),
             config.max_module_cache_size,
+            config.max_compile_gas,
         )));
 
         // Create an empty placeholder execution context, with shared atomic access
@@ -121,6 +124,8 @@ impl ExecutionState {
             active_history,
             // empty final event store: it is not recovered through bootstrap
             final_events: Default::default(),
+            // no final slot executed yet: gas usage is not recovered through bootstrap
+            final_gas_usage: Default::default(),
             // no active slots executed yet: set active_cursor to the last final block
             active_cursor: last_final_slot,
             final_cursor: last_final_slot,
@@ -167,12 +172,48 @@ impl ExecutionState {
             self.active_cursor = self.final_cursor;
         }
 
+        // keep track of the gas used by the latest final slot of each thread
+        self.final_gas_usage
+            .push_back((exec_out.slot, exec_out.gas_used));
+        if self.final_gas_usage.len() > self.config.thread_count as usize {
+            self.final_gas_usage.pop_front();
+        }
+
         // append generated events to the final event store
         exec_out.events.finalize();
         self.final_events.extend(exec_out.events);
         self.final_events.prune(self.config.max_final_events);
     }
 
+    /// Gets the total gas consumed by the operations and asynchronous messages of an executed slot.
+    ///
+    /// # Arguments
+    /// * `slot`: the executed slot
+    ///
+    /// # Returns
+    /// The gas used at that slot, or None if the slot is neither in the active history
+    /// nor among the latest final slots of the threads
+    pub fn get_slot_gas_usage(&self, slot: &Slot) -> Option<u64> {
+        if let Some(exec_out) = self
+            .active_history
+            .read()
+            .0
+            .iter()
+            .find(|exec_out| &exec_out.slot == slot)
+        {
+            return Some(exec_out.gas_used);
+        }
+        self.final_gas_usage
+            .iter()
+            .find(|(final_slot, _)| final_slot == slot)
+            .map(|(_, gas_used)| *gas_used)
+    }
+
+    /// Gets the statistics of the compiled module cache
+    pub fn get_module_cache_stats(&self) -> ModuleCacheStats {
+        self.module_cache.read().get_stats()
+    }
+
     /// Applies an execution output to the active (non-final) state
     /// The newly active final output should be from the slot just after the last executed active slot
     ///
@@ -209,10 +250,7 @@ impl ExecutionState {
         block_credits: &mut Amount,
     ) -> Result<(), ExecutionError> {
         // check validity period
-        if !(operation
-            .get_validity_range(self.config.operation_validity_period)
-            .contains(&block_slot.period))
-        {
+        if !operation.is_valid_in_period(block_slot.period, self.config.operation_validity_period) {
             return Err(ExecutionError::InvalidSlotRange);
         }
 
@@ -279,6 +317,7 @@ impl ExecutionState {
 
             // set the context max gas to match the one defined in the operation
             context.max_gas = operation.get_gas_usage();
+            context.interface_gas_used = 0;
 
             // set the creator address
             context.creator_address = Some(operation.content_creator_address);
@@ -286,6 +325,9 @@ impl ExecutionState {
             // set the context origin operation ID
             context.origin_operation_id = Some(operation_id);
 
+            // start counting the events emitted by the operation
+            context.execution_start_contract_event_count = context.contract_event_count;
+
             // execution context lock dropped here because the op-specific execution functions below acquire it again
         }
 
@@ -363,6 +405,7 @@ impl ExecutionState {
             coins: Amount::default(),
             owned_addresses: vec![seller_addr],
             operation_datastore: None,
+            is_read_only: false,
         }];
 
         // try to sell the rolls
@@ -402,6 +445,7 @@ impl ExecutionState {
             coins: Default::default(),
             owned_addresses: vec![buyer_addr],
             operation_datastore: None,
+            is_read_only: false,
         }];
 
         // compute the amount of coins to spend
@@ -460,6 +504,7 @@ impl ExecutionState {
             coins: *amount,
             owned_addresses: vec![sender_addr],
             operation_datastore: None,
+            is_read_only: false,
         }];
 
         // send `roll_price` * `roll_count` coins from the sender to the recipient
@@ -510,23 +555,30 @@ impl ExecutionState {
                 coins: Amount::zero(),
                 owned_addresses: vec![sender_addr],
                 operation_datastore: Some(datastore.clone()),
+                is_read_only: false,
             }];
         };
 
         // run the VM on the bytecode contained in the operation
-        let module = RuntimeModule::new(bytecode, *max_gas, self.config.gas_costs.clone())
-            .map_err(|err| {
-                ExecutionError::RuntimeError(format!(
-                    "compilation error in execute_executesc_op: {}",
-                    err
-                ))
-            })?;
-        match massa_sc_runtime::run_main(
+        let module = RuntimeModule::new(
+            bytecode,
+            self.config.max_compile_gas,
+            self.config.gas_costs.clone(),
+        )
+        .map_err(|err| {
+            ExecutionError::RuntimeError(format!(
+                "compilation error in execute_executesc_op: {}",
+                err
+            ))
+        })?;
+        let response = massa_sc_runtime::run_main(
             &*self.execution_interface,
             module,
             *max_gas,
             self.config.gas_costs.clone(),
-        ) {
+        );
+        self.record_gas_usage(*max_gas, &response);
+        match response {
             Ok(_response) => {}
             Err(err) => {
                 return Err(ExecutionError::RuntimeError(format!(
@@ -579,15 +631,25 @@ impl ExecutionState {
                     coins: Default::default(),
                     owned_addresses: vec![sender_addr],
                     operation_datastore: None,
+                    is_read_only: false,
                 },
                 ExecutionStackElement {
                     address: target_addr,
                     coins: Default::default(),
                     owned_addresses: vec![target_addr],
                     operation_datastore: None,
+                    is_read_only: false,
                 },
             ];
 
+            // refuse calls targeting a denied contract
+            if self.config.denied_contract_addresses.contains(&target_addr) {
+                return Err(ExecutionError::RuntimeError(format!(
+                    "calls to contract {} are denied",
+                    target_addr
+                )));
+            }
+
             // Debit the sender's balance with the coins to transfer
             if let Err(err) = context.transfer_coins(Some(sender_addr), None, coins, false) {
                 return Err(ExecutionError::RuntimeError(format!(
@@ -616,14 +678,16 @@ impl ExecutionState {
         // run the VM on the bytecode loaded from the target address
         let mut module_lock = self.module_cache.write();
         let module = module_lock.get_module(&bytecode, max_gas)?;
-        match massa_sc_runtime::run_function(
+        let response = massa_sc_runtime::run_function(
             &*self.execution_interface,
             module.clone(),
             target_func,
             param,
             max_gas,
             self.config.gas_costs.clone(),
-        ) {
+        );
+        self.record_gas_usage(max_gas, &response);
+        match response {
             Ok(Response { init_cost, .. }) => {
                 module_lock.save_module(&bytecode, module, init_cost);
                 Ok(())
@@ -635,6 +699,21 @@ impl ExecutionState {
         }
     }
 
+    /// Adds the gas consumed by a VM run to the gas usage of the slot being executed.
+    /// A failed run does not report its remaining gas, so it is accounted for its whole `max_gas`.
+    ///
+    /// # Arguments
+    /// * `max_gas`: maximum gas allowed for the run
+    /// * `response`: result of the VM run
+    fn record_gas_usage<E>(&self, max_gas: u64, response: &Result<Response, E>) {
+        let gas_used = match response {
+            Ok(Response { remaining_gas, .. }) => max_gas.saturating_sub(*remaining_gas),
+            Err(_) => max_gas,
+        };
+        let mut context = context_guard!(self);
+        context.gas_used = context.gas_used.saturating_add(gas_used);
+    }
+
     /// Tries to execute an asynchronous message
     /// If the execution failed reimburse the message sender.
     ///
@@ -652,22 +731,41 @@ impl ExecutionState {
             let mut context = context_guard!(self);
             context_snapshot = context.get_snapshot();
             context.max_gas = message.max_gas;
+            context.interface_gas_used = 0;
             context.creator_address = None;
+            context.execution_start_contract_event_count = context.contract_event_count;
             context.stack = vec![
                 ExecutionStackElement {
                     address: message.sender,
                     coins: message.coins,
                     owned_addresses: vec![message.sender],
                     operation_datastore: None,
+                    is_read_only: false,
                 },
                 ExecutionStackElement {
                     address: message.destination,
                     coins: message.coins,
                     owned_addresses: vec![message.destination],
                     operation_datastore: None,
+                    is_read_only: false,
                 },
             ];
 
+            // refuse messages targeting a denied contract: reimburse sender and quit
+            if self
+                .config
+                .denied_contract_addresses
+                .contains(&message.destination)
+            {
+                let err = ExecutionError::RuntimeError(format!(
+                    "calls to contract {} are denied",
+                    message.destination
+                ));
+                context.reset_to_snapshot(context_snapshot, err.clone());
+                context.cancel_async_message(&message);
+                return Err(err);
+            }
+
             // If there is no target bytecode or if message data is invalid,
             // reimburse sender with coins and quit
             let bytecode = match bytecode {
@@ -706,14 +804,16 @@ impl ExecutionState {
         // run the VM on the bytecode contained in the operation
         let mut module_lock = self.module_cache.write();
         let module = module_lock.get_module(&bytecode, message.max_gas)?;
-        match massa_sc_runtime::run_function(
+        let response = massa_sc_runtime::run_function(
             &*self.execution_interface,
             module.clone(),
             &message.handler,
             &message.data,
             message.max_gas,
             self.config.gas_costs.clone(),
-        ) {
+        );
+        self.record_gas_usage(message.max_gas, &response);
+        match response {
             Ok(Response { init_cost, .. }) => {
                 module_lock.save_module(&bytecode, module, init_cost);
                 Ok(())
@@ -915,8 +1015,83 @@ impl ExecutionState {
             context_guard!(self).update_production_stats(&producer_addr, *slot, None);
         }
 
-        // Finish slot and return the execution output
-        context_guard!(self).settle_slot()
+        // Finish slot
+        let mut exec_out = context_guard!(self).settle_slot();
+
+        // Report the roll count changes of the slot, outside of the execution context
+        if self.config.emit_roll_change_events {
+            self.emit_roll_change_events(&mut exec_out);
+        }
+
+        // Return the execution output
+        exec_out
+    }
+
+    /// Appends to the output of a settled slot an event for every address
+    /// whose roll count changed during that slot.
+    ///
+    /// Those events are node-local: they are generated after the execution of the slot
+    /// so that they do not influence the execution context.
+    ///
+    /// # Arguments
+    /// * `exec_out`: the settled output of the slot, must not be applied to the active history yet
+    fn emit_roll_change_events(&self, exec_out: &mut ExecutionOutput) {
+        let mut changes: Vec<(&Address, &u64)> = exec_out
+            .state_changes
+            .pos_changes
+            .roll_changes
+            .iter()
+            .collect();
+        changes.sort_unstable_by_key(|(addr, _)| **addr);
+        let mut index_in_slot = exec_out
+            .events
+            .0
+            .back()
+            .map_or(0, |event| event.context.index_in_slot + 1);
+        let mut events = Vec::new();
+        for (addr, new_count) in changes {
+            let (_, previous_count) = self.get_final_and_candidate_rolls(addr);
+            if *new_count == previous_count {
+                continue;
+            }
+            let delta = if *new_count > previous_count {
+                i64::try_from(new_count - previous_count).unwrap_or(i64::MAX)
+            } else {
+                i64::try_from(previous_count - new_count).map_or(i64::MIN, |count| -count)
+            };
+            events.push(SCOutputEvent {
+                context: EventExecutionContext {
+                    slot: exec_out.slot,
+                    block: exec_out.block_id,
+                    read_only: false,
+                    index_in_slot,
+                    call_stack: VecDeque::new(),
+                    origin_operation_id: None,
+                    is_final: false,
+                    is_error: false,
+                },
+                data: serde_json::json!({
+                    "roll_change": {
+                        "address": addr.to_string(),
+                        "delta": delta,
+                        "new_count": new_count,
+                    }
+                })
+                .to_string(),
+            });
+            index_in_slot += 1;
+        }
+        for event in events {
+            exec_out.events.push(event);
+        }
+    }
+
+    /// Reports to the stats the time the ABIs spent holding the context lock during the last executed slot
+    #[cfg(feature = "context_lock_metrics")]
+    fn register_context_lock_hold_time(&mut self) {
+        let hold_time = context_guard!(self).context_lock_hold_time;
+        self.stats_counter
+            .register_context_lock_hold_time(hold_time);
     }
 
     /// Execute a candidate slot
@@ -955,6 +1130,8 @@ impl ExecutionState {
 
         let exec_out = self.execute_slot(slot, exec_target, selector);
         debug!("execute_candidate_slot: execution finished");
+        #[cfg(feature = "context_lock_metrics")]
+        self.register_context_lock_hold_time();
 
         // apply execution output to active state
         self.apply_active_execution_output(exec_out);
@@ -1016,6 +1193,8 @@ impl ExecutionState {
         debug!("execute_final_slot: execution started");
         let exec_out = self.execute_slot(slot, exec_target, selector);
         debug!("execute_final_slot: execution finished");
+        #[cfg(feature = "context_lock_metrics")]
+        self.register_context_lock_hold_time();
 
         // apply execution output to final state
         self.apply_final_execution_output(exec_out);
@@ -1059,7 +1238,7 @@ impl ExecutionState {
         };
 
         // create a readonly execution context
-        let execution_context = ExecutionContext::readonly(
+        let mut execution_context = ExecutionContext::readonly(
             self.config.clone(),
             slot,
             req.max_gas,
@@ -1068,6 +1247,7 @@ impl ExecutionState {
             self.active_history.clone(),
             self.module_cache.clone(),
         );
+        execution_context.view_only = req.view_only;
 
         // run the interpreter according to the target type
         let exec_response = match req.target {
@@ -1076,14 +1256,17 @@ impl ExecutionState {
                 *context_guard!(self) = execution_context;
 
                 // run the bytecode's main function
-                let module =
-                    RuntimeModule::new(&bytecode, req.max_gas, self.config.gas_costs.clone())
-                        .map_err(|err| {
-                            ExecutionError::RuntimeError(format!(
-                                "compilation error in execute_readonly_request: {}",
-                                err
-                            ))
-                        })?;
+                let module = RuntimeModule::new(
+                    &bytecode,
+                    self.config.max_compile_gas,
+                    self.config.gas_costs.clone(),
+                )
+                .map_err(|err| {
+                    ExecutionError::RuntimeError(format!(
+                        "compilation error in execute_readonly_request: {}",
+                        err
+                    ))
+                })?;
                 massa_sc_runtime::run_main(
                     &*self.execution_interface,
                     module,
@@ -1102,6 +1285,14 @@ impl ExecutionState {
                 target_func,
                 parameter,
             } => {
+                // refuse calls targeting a denied contract
+                if self.config.denied_contract_addresses.contains(&target_addr) {
+                    return Err(ExecutionError::RuntimeError(format!(
+                        "calls to contract {} are denied",
+                        target_addr
+                    )));
+                }
+
                 // get the bytecode, default to an empty vector
                 let bytecode = execution_context
                     .get_bytecode(&target_addr)
@@ -27,12 +27,64 @@ use tracing::debug;
 use massa_models::datastore::Datastore;
 
 /// helper for locking the context mutex
+#[cfg(not(feature = "context_lock_metrics"))]
 macro_rules! context_guard {
     ($self:ident) => {
         $self.context.lock()
     };
 }
 
+/// helper for locking the context mutex while measuring the time it is held
+#[cfg(feature = "context_lock_metrics")]
+macro_rules! context_guard {
+    ($self:ident) => {
+        TimedContextGuard::new($self.context.lock())
+    };
+}
+
+/// Guard of the execution context lock that adds the time it was held
+/// to `ExecutionContext::context_lock_hold_time` when dropped.
+#[cfg(feature = "context_lock_metrics")]
+struct TimedContextGuard<'a> {
+    guard: parking_lot::MutexGuard<'a, ExecutionContext>,
+    locked_at: std::time::Instant,
+}
+
+#[cfg(feature = "context_lock_metrics")]
+impl<'a> TimedContextGuard<'a> {
+    fn new(guard: parking_lot::MutexGuard<'a, ExecutionContext>) -> Self {
+        TimedContextGuard {
+            guard,
+            locked_at: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "context_lock_metrics")]
+impl std::ops::Deref for TimedContextGuard<'_> {
+    type Target = ExecutionContext;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+#[cfg(feature = "context_lock_metrics")]
+impl std::ops::DerefMut for TimedContextGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+#[cfg(feature = "context_lock_metrics")]
+impl Drop for TimedContextGuard<'_> {
+    fn drop(&mut self) {
+        // the lock is still held here, so the update is consistent
+        let held = self.locked_at.elapsed();
+        self.guard.context_lock_hold_time += held;
+    }
+}
+
 /// an implementation of the Interface trait (see massa-sc-runtime crate)
 #[derive(Clone)]
 pub struct InterfaceImpl {
@@ -52,6 +104,16 @@ impl InterfaceImpl {
         InterfaceImpl { config, context }
     }
 
+    /// Returns the number of events emitted so far by smart contracts
+    /// during the current operation or asynchronous message.
+    /// Error events and events generated by the node itself are not counted.
+    pub fn get_emitted_event_count(&self) -> Result<u64> {
+        let context = context_guard!(self);
+        Ok(context
+            .contract_event_count
+            .saturating_sub(context.execution_start_contract_event_count))
+    }
+
     #[cfg(any(feature = "gas_calibration", feature = "benchmarking"))]
     /// Used to create an default interface to run SC in a test environment
     pub fn new_default(
@@ -65,7 +127,11 @@ impl InterfaceImpl {
 
         let config = ExecutionConfig::default();
         let (final_state, _tempfile, _tempdir) = crate::tests::get_sample_state().unwrap();
-        let module_cache = Arc::new(RwLock::new(ModuleCache::new(GasCosts::default(), 1000)));
+        let module_cache = Arc::new(RwLock::new(ModuleCache::new(
+            GasCosts::default(),
+            1000,
+            config.max_compile_gas,
+        )));
         let mut execution_context = ExecutionContext::new(
             config.clone(),
             final_state,
@@ -77,6 +143,7 @@ impl InterfaceImpl {
             coins: Amount::zero(),
             owned_addresses: vec![sender_addr],
             operation_datastore,
+            is_read_only: false,
         }];
         execution_context.speculative_ledger.added_changes.0.insert(
             sender_addr,
@@ -127,6 +194,11 @@ impl Interface for InterfaceImpl {
         // get target address
         let to_address = massa_models::address::Address::from_str(address)?;
 
+        // refuse calls targeting a denied contract
+        if self.config.denied_contract_addresses.contains(&to_address) {
+            bail!("calls to contract {} are denied", to_address);
+        }
+
         // write-lock context
         let mut context = context_guard!(self);
 
@@ -136,31 +208,45 @@ impl Interface for InterfaceImpl {
             None => bail!("bytecode not found for address {}", to_address),
         };
 
-        // get caller address
-        let from_address = match context.stack.last() {
-            Some(addr) => addr.address,
+        // get caller address, and whether the caller is read-only so that the callee inherits it
+        let (from_address, is_read_only) = match context.stack.last() {
+            Some(elem) => (elem.address, elem.is_read_only),
             _ => bail!("failed to read call stack current address"),
         };
 
-        // transfer coins from caller to target address
-        let coins = massa_models::amount::Amount::from_raw(raw_coins);
-        if let Err(err) = context.transfer_coins(Some(from_address), Some(to_address), coins, true)
-        {
+        // refuse calls that would make the call stack deeper than allowed
+        if context.stack.len() as u64 >= self.config.max_call_depth {
             bail!(
-                "error transferring {} coins from {} to {}: {}",
-                coins,
-                from_address,
-                to_address,
-                err
+                "max call depth of {} exceeded when calling {}",
+                self.config.max_call_depth,
+                to_address
             );
         }
 
+        // transfer coins from caller to target address.
+        // View-only and read-only calls can't transfer coins but may still call other contracts without coins.
+        let coins = massa_models::amount::Amount::from_raw(raw_coins);
+        if !context.is_write_forbidden() || !coins.is_zero() {
+            if let Err(err) =
+                context.transfer_coins(Some(from_address), Some(to_address), coins, true)
+            {
+                bail!(
+                    "error transferring {} coins from {} to {}: {}",
+                    coins,
+                    from_address,
+                    to_address,
+                    err
+                );
+            }
+        }
+
         // push a new call stack element on top of the current call stack
         context.stack.push(ExecutionStackElement {
             address: to_address,
             coins,
             owned_addresses: vec![to_address],
             operation_datastore: None,
+            is_read_only,
         });
 
         // return the target bytecode
@@ -534,7 +620,7 @@ impl Interface for InterfaceImpl {
     /// # Returns
     /// true if the signature verification succeeded, false otherwise
     fn signature_verify(&self, data: &[u8], signature: &str, public_key: &str) -> Result<bool> {
-        let signature = match massa_signature::Signature::from_bs58_check(signature) {
+        let signature = match massa_signature::Signature::from_str(signature) {
             Ok(sig) => sig,
             Err(_) => return Ok(false),
         };
@@ -587,7 +673,8 @@ impl Interface for InterfaceImpl {
     ///
     /// # Returns
     /// A vector with the string representation of each owned address.
-    /// Note that the ordering of this vector is deterministic and conserved.
+    /// The addresses are in insertion order: the current address first,
+    /// then the addresses created by the current call in creation order.
     fn get_owned_addresses(&self) -> Result<Vec<String>> {
         Ok(context_guard!(self)
             .get_current_owned_addresses()?
@@ -624,7 +711,7 @@ impl Interface for InterfaceImpl {
     fn generate_event(&self, data: String) -> Result<()> {
         let mut context = context_guard!(self);
         let event = context.event_create(data, false);
-        context.event_emit(event);
+        context.try_event_emit(event)?;
         Ok(())
     }
 
@@ -1,4 +1,4 @@
-use massa_execution_exports::ExecutionError;
+use massa_execution_exports::{ExecutionError, ModuleCacheStats};
 use massa_hash::Hash;
 use massa_models::prehash::BuildHashMapper;
 use massa_sc_runtime::{GasCosts, RuntimeModule};
@@ -15,14 +15,26 @@ pub type PreHashLruMap<K, V> = LruMap<K, V, ByLength, BuildHashMapper<K>>;
 /// * value.1: instance initialization cost
 pub struct ModuleCache {
     gas_costs: GasCosts,
+    /// max gas that can be spent compiling a module
+    compile_limit: u64,
     cache: PreHashLruMap<Hash, (RuntimeModule, u64)>,
+    /// number of requests served from the cache
+    hits: u64,
+    /// number of requests that required a compilation
+    misses: u64,
+    /// number of modules evicted by the LRU policy
+    evictions: u64,
 }
 
 impl ModuleCache {
-    pub fn new(gas_costs: GasCosts, cache_size: u32) -> Self {
+    pub fn new(gas_costs: GasCosts, cache_size: u32, compile_limit: u64) -> Self {
         Self {
             gas_costs,
+            compile_limit,
             cache: LruMap::with_hasher(ByLength::new(cache_size), BuildHashMapper::default()),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
         }
     }
 
@@ -31,35 +43,58 @@ impl ModuleCache {
     /// * move it up in the LRU cache
     ///
     /// If the module is not contained in the cache:
-    /// * create the module
+    /// * create the module, its compilation being metered against the compile limit
     /// * retrieve it
+    ///
+    /// The compilation cost is bounded by the cache's compile limit
+    /// while `exec_limit` only bounds the execution of the module.
     pub fn get_module(
         &mut self,
         bytecode: &[u8],
-        limit: u64,
+        exec_limit: u64,
     ) -> Result<RuntimeModule, ExecutionError> {
         if let Some((cached_module, init_cost)) = self.cache.get(&Hash::compute_from(bytecode)) {
-            if limit < *init_cost {
+            if exec_limit < *init_cost {
                 return Err(ExecutionError::RuntimeError(
                     "given gas cannot cover the initialization costs".to_string(),
                 ));
             }
+            self.hits = self.hits.saturating_add(1);
             Ok(cached_module.clone())
         } else {
+            self.misses = self.misses.saturating_add(1);
             let new_module =
-                RuntimeModule::new(bytecode, limit, self.gas_costs.clone()).map_err(|err| {
-                    ExecutionError::RuntimeError(format!(
-                        "compilation of missing cache module failed: {}",
-                        err
-                    ))
-                })?;
+                RuntimeModule::new(bytecode, self.compile_limit, self.gas_costs.clone()).map_err(
+                    |err| {
+                        ExecutionError::RuntimeError(format!(
+                            "compilation of missing cache module within the compile gas limit {} failed: {}",
+                            self.compile_limit, err
+                        ))
+                    },
+                )?;
             Ok(new_module)
         }
     }
 
     /// Save a module in the cache
     pub fn save_module(&mut self, bytecode: &[u8], module: RuntimeModule, init_cost: u64) {
-        self.cache
-            .insert(Hash::compute_from(bytecode), (module, init_cost));
+        let hash = Hash::compute_from(bytecode);
+        let is_new = self.cache.peek(&hash).is_none();
+        let len_before = self.cache.len();
+        let inserted = self.cache.insert(hash, (module, init_cost));
+        // a new entry that did not grow the cache pushed out the least recently used one
+        if inserted && is_new && self.cache.len() <= len_before {
+            self.evictions = self.evictions.saturating_add(1);
+        }
+    }
+
+    /// Get the hit, miss and eviction counters of the cache
+    pub fn get_stats(&self) -> ModuleCacheStats {
+        ModuleCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            cached_modules: self.cache.len(),
+        }
     }
 }
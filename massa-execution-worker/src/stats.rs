@@ -4,6 +4,7 @@ use massa_models::slot::Slot;
 use massa_models::stats::ExecutionStats;
 use massa_time::MassaTime;
 use std::collections::VecDeque;
+use std::time::Duration;
 
 /// Execution statistics counter
 pub struct ExecutionStatsCounter {
@@ -13,6 +14,8 @@ pub struct ExecutionStatsCounter {
     final_blocks: VecDeque<(usize, MassaTime)>,
     /// final operations executed in the time window (count, instant)
     final_executed_ops: VecDeque<(usize, MassaTime)>,
+    /// time the ABIs held the context lock for each slot executed in the time window (duration, instant)
+    context_lock_hold_times: VecDeque<(Duration, MassaTime)>,
 }
 
 impl ExecutionStatsCounter {
@@ -22,6 +25,7 @@ impl ExecutionStatsCounter {
             time_window_duration,
             final_blocks: Default::default(),
             final_executed_ops: Default::default(),
+            context_lock_hold_times: Default::default(),
         }
     }
 
@@ -46,6 +50,15 @@ impl ExecutionStatsCounter {
                 break;
             }
         }
+
+        // prune context lock hold times
+        while let Some((_, t)) = self.context_lock_hold_times.front() {
+            if t < &start_time {
+                self.context_lock_hold_times.pop_front();
+            } else {
+                break;
+            }
+        }
     }
 
     /// register final blocks
@@ -62,6 +75,15 @@ impl ExecutionStatsCounter {
         self.refresh(current_time);
     }
 
+    /// register the time the ABIs held the context lock during the execution of a slot
+    #[cfg(feature = "context_lock_metrics")]
+    pub fn register_context_lock_hold_time(&mut self, hold_time: Duration) {
+        let current_time = MassaTime::now().expect("could not get current time");
+        self.context_lock_hold_times
+            .push_back((hold_time, current_time));
+        self.refresh(current_time);
+    }
+
     /// get statistics
     pub fn get_stats(&self, active_cursor: Slot) -> ExecutionStats {
         let current_time = MassaTime::now().expect("could not get current time");
@@ -80,6 +102,14 @@ impl ExecutionStatsCounter {
             time_window_start: start_time,
             time_window_end: current_time,
             active_cursor,
+            context_lock_hold_time: MassaTime::from_millis(
+                self.context_lock_hold_times
+                    .iter()
+                    .filter(|(_, t)| t >= &start_time && t <= &current_time)
+                    .map(|(hold_time, _)| *hold_time)
+                    .sum::<Duration>()
+                    .as_millis() as u64,
+            ),
         }
     }
 }
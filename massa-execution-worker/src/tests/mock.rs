@@ -1,6 +1,5 @@
 use massa_execution_exports::ExecutionError;
 use massa_final_state::{FinalState, FinalStateConfig};
-use massa_hash::Hash;
 use massa_ledger_exports::{LedgerConfig, LedgerController, LedgerEntry, LedgerError};
 use massa_ledger_worker::FinalLedger;
 use massa_models::{
@@ -9,7 +8,7 @@ use massa_models::{
     block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer},
     config::THREAD_COUNT,
-    operation::SecureShareOperation,
+    operation::{compute_operations_merkle_root, SecureShareOperation},
     secure_share::SecureShareContent,
     slot::Slot,
 };
@@ -137,11 +136,8 @@ pub fn create_block(
     operations: Vec<SecureShareOperation>,
     slot: Slot,
 ) -> Result<SecureShareBlock, ExecutionError> {
-    let operation_merkle_root = Hash::compute_from(
-        &operations.iter().fold(Vec::new(), |acc, v| {
-            [acc, v.serialized_data.clone()].concat()
-        })[..],
-    );
+    let operation_merkle_root =
+        compute_operations_merkle_root(&operations.iter().map(|op| op.id).collect::<Vec<_>>());
 
     let header = BlockHeader::new_verifiable(
         BlockHeader {
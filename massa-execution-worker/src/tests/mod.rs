@@ -9,5 +9,8 @@ mod scenarios_mandatories;
 #[cfg(all(not(feature = "gas_calibration"), not(feature = "benchmarking")))]
 mod tests_active_history;
 
+#[cfg(all(not(feature = "gas_calibration"), not(feature = "benchmarking")))]
+mod tests_interface_impl;
+
 #[cfg(any(feature = "gas_calibration", feature = "benchmarking"))]
 pub use mock::get_sample_state;
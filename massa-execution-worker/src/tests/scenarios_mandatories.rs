@@ -85,6 +85,7 @@ fn test_readonly_execution() {
                 include_bytes!("./wasm/event_test.wasm").to_vec(),
             ),
             is_final: true,
+            view_only: false,
         })
         .expect("readonly execution failed");
     assert_eq!(res.out.slot, Slot::new(1, 0));
@@ -99,6 +100,7 @@ fn test_readonly_execution() {
                 include_bytes!("./wasm/event_test.wasm").to_vec(),
             ),
             is_final: false,
+            view_only: false,
         })
         .expect("readonly execution failed");
     assert!(res.out.slot.period > 8);
@@ -106,6 +108,50 @@ fn test_readonly_execution() {
     manager.stop();
 }
 
+#[test]
+#[serial]
+fn test_readonly_call_denied_contract() {
+    // deny calls to a random address
+    let denied = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let exec_cfg = ExecutionConfig {
+        t0: 100.into(),
+        cursor_delay: 0.into(),
+        denied_contract_addresses: vec![denied].into_iter().collect(),
+        ..ExecutionConfig::default()
+    };
+    // get a sample final state
+    let (sample_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+    // init the storage
+    let storage = Storage::create_root();
+    // start the execution worker
+    let (mut manager, controller) = start_execution_worker(
+        exec_cfg.clone(),
+        sample_state.clone(),
+        sample_state.read().pos_state.selector.clone(),
+    );
+    // initialize the execution system with genesis blocks
+    init_execution_worker(&exec_cfg, &storage, controller.clone());
+    std::thread::sleep(Duration::from_millis(1000));
+
+    // a read-only call targeting the denied address must fail
+    let err = controller
+        .execute_readonly_request(ReadOnlyExecutionRequest {
+            max_gas: 1_000_000,
+            call_stack: vec![],
+            target: ReadOnlyExecutionTarget::FunctionCall {
+                target_addr: denied,
+                target_func: "main".to_string(),
+                parameter: vec![],
+            },
+            is_final: true,
+            view_only: false,
+        })
+        .unwrap_err();
+    assert!(err.to_string().contains("denied"));
+
+    manager.stop();
+}
+
 /// Feeds the execution worker with genesis blocks to start it
 fn init_execution_worker(
     config: &ExecutionConfig,
@@ -827,6 +873,79 @@ pub fn roll_buy() {
     manager.stop();
 }
 
+#[test]
+#[serial]
+pub fn roll_change_events() {
+    // setup the period duration and enable the roll change events
+    let exec_cfg = ExecutionConfig {
+        t0: 100.into(),
+        cursor_delay: 0.into(),
+        emit_roll_change_events: true,
+        ..ExecutionConfig::default()
+    };
+    // get a sample final state
+    let (sample_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+
+    // init the storage
+    let mut storage = Storage::create_root();
+    // start the execution worker
+    let (mut manager, controller) = start_execution_worker(
+        exec_cfg.clone(),
+        sample_state.clone(),
+        sample_state.read().pos_state.selector.clone(),
+    );
+    // initialize the execution system with genesis blocks
+    init_execution_worker(&exec_cfg, &storage, controller.clone());
+    // generate the keypair and its corresponding address
+    let keypair = KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+    let address = Address::from_public_key(&keypair.get_public_key());
+    // create the roll buy operation
+    let operation = Operation::new_verifiable(
+        Operation {
+            fee: Amount::zero(),
+            expire_period: 10,
+            op: OperationType::RollBuy { roll_count: 10 },
+        },
+        OperationSerializer::new(),
+        &keypair,
+    )
+    .unwrap();
+    // create the block containing the roll buy operation and finalize it
+    storage.store_operations(vec![operation.clone()]);
+    let block = create_block(KeyPair::generate(), vec![operation], Slot::new(1, 0)).unwrap();
+    storage.store_block(block.clone());
+    let mut finalized_blocks: HashMap<Slot, BlockId> = Default::default();
+    finalized_blocks.insert(block.content.header.content.slot, block.id);
+    let mut block_storage: PreHashMap<BlockId, Storage> = Default::default();
+    block_storage.insert(block.id, storage.clone());
+    controller.update_blockclique_status(
+        finalized_blocks,
+        Default::default(),
+        block_storage.clone(),
+    );
+    std::thread::sleep(Duration::from_millis(10));
+    // check that a single roll change event was emitted for the purchase
+    let events = controller.get_filtered_sc_output_event(EventFilter {
+        start: Some(Slot::new(1, 0)),
+        end: Some(Slot::new(2, 0)),
+        ..Default::default()
+    });
+    let roll_changes: Vec<serde_json::Value> = events
+        .iter()
+        .filter_map(|event| serde_json::from_str::<serde_json::Value>(&event.data).ok())
+        .filter(|data| data.get("roll_change").is_some())
+        .collect();
+    assert_eq!(roll_changes.len(), 1, "one roll change event was expected");
+    assert_eq!(
+        roll_changes[0]["roll_change"]["address"],
+        address.to_string()
+    );
+    assert_eq!(roll_changes[0]["roll_change"]["delta"], 10);
+    assert_eq!(roll_changes[0]["roll_change"]["new_count"], 110);
+    // stop the execution controller
+    manager.stop();
+}
+
 #[test]
 #[serial]
 pub fn roll_sell() {
@@ -1028,6 +1147,67 @@ fn sc_execution_error() {
     manager.stop();
 }
 
+/// Check that the gas reported for an executed slot is the sum of the gas used by its operations
+#[test]
+#[serial]
+fn slot_gas_usage() {
+    // setup the period duration
+    let exec_cfg = ExecutionConfig {
+        t0: 100.into(),
+        cursor_delay: 0.into(),
+        ..ExecutionConfig::default()
+    };
+    // get a sample final state
+    let (sample_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+
+    // init the storage
+    let mut storage = Storage::create_root();
+    // start the execution worker
+    let (mut manager, controller) = start_execution_worker(
+        exec_cfg.clone(),
+        sample_state.clone(),
+        sample_state.read().pos_state.selector.clone(),
+    );
+    // initialize the execution system with genesis blocks
+    init_execution_worker(&exec_cfg, &storage, controller.clone());
+    // keypair associated to thread 0
+    let keypair = KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+    // load bytecode
+    // you can check the source code of the following wasm file in massa-unit-tests-src
+    let bytecode = include_bytes!("./wasm/execution_error.wasm");
+    // create two distinct failing operations: each one uses all of its max gas
+    let operations: Vec<_> = (0u8..2)
+        .map(|index| {
+            let mut datastore = BTreeMap::new();
+            datastore.insert(vec![index], vec![]);
+            create_execute_sc_operation(&keypair, bytecode, datastore).unwrap()
+        })
+        .collect();
+    let expected_gas: u64 = operations.iter().map(|op| op.get_gas_usage()).sum();
+    storage.store_operations(operations.clone());
+    let block = create_block(KeyPair::generate(), operations, Slot::new(1, 0)).unwrap();
+    // store the block in storage
+    storage.store_block(block.clone());
+    // set our block as a final block
+    let mut finalized_blocks: HashMap<Slot, BlockId> = Default::default();
+    finalized_blocks.insert(block.content.header.content.slot, block.id);
+    let mut block_storage: PreHashMap<BlockId, Storage> = Default::default();
+    block_storage.insert(block.id, storage.clone());
+    controller.update_blockclique_status(
+        finalized_blocks,
+        Default::default(),
+        block_storage.clone(),
+    );
+    std::thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(
+        controller.get_slot_gas_usage(Slot::new(1, 0)),
+        Some(expected_gas)
+    );
+    // stop the execution controller
+    manager.stop();
+}
+
 #[test]
 #[serial]
 fn sc_datastore() {
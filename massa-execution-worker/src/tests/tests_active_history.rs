@@ -50,6 +50,7 @@ fn test_active_history_deferred_credits() {
             executed_ops_changes: Default::default(),
         },
         events: Default::default(),
+        gas_used: 0,
     };
 
     let active_history = ActiveHistory {
@@ -0,0 +1,687 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+use crate::active_history::ActiveHistory;
+use crate::context::ExecutionContext;
+use crate::interface_impl::InterfaceImpl;
+use crate::module_cache::ModuleCache;
+use crate::tests::mock::get_sample_state;
+use massa_execution_exports::{ExecutionConfig, ExecutionError, ExecutionStackElement};
+use massa_models::{address::Address, amount::Amount, slot::Slot};
+use massa_sc_runtime::Interface;
+use massa_signature::KeyPair;
+use parking_lot::{Mutex, RwLock};
+use serial_test::serial;
+use std::{str::FromStr, sync::Arc};
+use tempfile::{NamedTempFile, TempDir};
+
+/// Create an `InterfaceImpl` on top of an empty execution context built from a sample final state
+fn get_sample_interface(
+    config: ExecutionConfig,
+) -> (
+    InterfaceImpl,
+    Arc<Mutex<ExecutionContext>>,
+    NamedTempFile,
+    TempDir,
+) {
+    let (final_state, keep_file, keep_dir) = get_sample_state().unwrap();
+    let module_cache = Arc::new(RwLock::new(ModuleCache::new(
+        config.gas_costs.clone(),
+        config.max_module_cache_size,
+        config.max_compile_gas,
+    )));
+    let context = Arc::new(Mutex::new(ExecutionContext::new(
+        config.clone(),
+        final_state,
+        Arc::new(RwLock::new(ActiveHistory::default())),
+        module_cache,
+    )));
+    (
+        InterfaceImpl::new(config, context.clone()),
+        context,
+        keep_file,
+        keep_dir,
+    )
+}
+
+/// Address funded in the sample final state (see `get_sample_state`)
+fn get_funded_address() -> Address {
+    let keypair = KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+    Address::from_public_key(&keypair.get_public_key())
+}
+
+/// Push a call stack element for `address` that owns itself
+fn push_stack_element(context: &Arc<Mutex<ExecutionContext>>, address: Address) {
+    context.lock().stack.push(ExecutionStackElement {
+        address,
+        coins: Amount::zero(),
+        owned_addresses: vec![address],
+        operation_datastore: None,
+        is_read_only: false,
+    });
+}
+
+#[test]
+#[serial]
+fn test_owned_addresses_ordering() {
+    let run = || {
+        let (interface, context, _keep_file, _keep_dir) =
+            get_sample_interface(ExecutionConfig::default());
+        let caller = get_funded_address();
+        push_stack_element(&context, caller);
+        let mut expected = vec![caller.to_string()];
+        for _ in 0..5 {
+            expected.push(interface.create_module(b"bytecode").unwrap());
+        }
+        let owned = interface.get_owned_addresses().unwrap();
+        assert_eq!(
+            owned, expected,
+            "owned addresses must follow insertion order"
+        );
+        owned
+    };
+    assert_eq!(
+        run(),
+        run(),
+        "owned addresses ordering must be deterministic"
+    );
+}
+
+#[test]
+#[serial]
+fn test_max_owned_addresses() {
+    let config = ExecutionConfig {
+        max_owned_addresses: 3,
+        ..ExecutionConfig::default()
+    };
+    let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+    push_stack_element(&context, get_funded_address());
+    interface.create_module(b"bytecode").unwrap();
+    interface.create_module(b"bytecode").unwrap();
+    let err = interface.create_module(b"bytecode").unwrap_err();
+    assert!(
+        err.to_string().contains("max owned addresses per call"),
+        "unexpected error: {}",
+        err
+    );
+    assert_eq!(interface.get_owned_addresses().unwrap().len(), 3);
+}
+
+#[test]
+#[serial]
+fn test_get_emitted_event_count() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    push_stack_element(&context, get_funded_address());
+    assert_eq!(interface.get_emitted_event_count().unwrap(), 0);
+    for expected_count in 1..=3 {
+        interface
+            .generate_event(format!("event {}", expected_count))
+            .unwrap();
+        assert_eq!(interface.get_emitted_event_count().unwrap(), expected_count);
+    }
+    // events that were not emitted by the contract are not counted
+    {
+        let mut context = context.lock();
+        let snapshot = context.get_snapshot();
+        context.reset_to_snapshot(snapshot, ExecutionError::RuntimeError("test".to_string()));
+        let event = context.event_create("node event".to_string(), false);
+        context.event_emit(event);
+    }
+    assert_eq!(interface.get_emitted_event_count().unwrap(), 3);
+    // a new operation starts counting from zero
+    {
+        let mut context = context.lock();
+        context.execution_start_contract_event_count = context.contract_event_count;
+    }
+    assert_eq!(interface.get_emitted_event_count().unwrap(), 0);
+    interface.generate_event("event 4".to_string()).unwrap();
+    assert_eq!(interface.get_emitted_event_count().unwrap(), 1);
+}
+
+/// Several transfers in a single execution must yield ledger changes applied in the same order
+/// and the same ledger hash on every run. The ledger hash must not depend on the order of the transfers.
+#[test]
+#[serial]
+fn test_transfer_coins_deterministic_changes() {
+    let recipients: Vec<Address> = (0..10)
+        .map(|_| Address::from_public_key(&KeyPair::generate().get_public_key()))
+        .collect();
+    let run = |recipients: &[Address]| {
+        let (interface, context, _keep_file, _keep_dir) =
+            get_sample_interface(ExecutionConfig::default());
+        push_stack_element(&context, get_funded_address());
+        for recipient in recipients {
+            interface
+                .transfer_coins(&recipient.to_string(), 10_000_000_000)
+                .unwrap();
+        }
+        let changes = context.lock().settle_slot().state_changes.ledger_changes;
+        let apply_order: Vec<Address> = changes.0.keys().copied().collect();
+        let (final_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+        final_state
+            .write()
+            .ledger
+            .apply_changes(changes, Slot::new(1, 0));
+        let ledger_hash = final_state.read().ledger.get_ledger_hash();
+        (apply_order, ledger_hash)
+    };
+
+    let (apply_order, ledger_hash) = run(&recipients);
+    assert_eq!(apply_order.len(), recipients.len() + 1);
+    assert_eq!(run(&recipients), (apply_order, ledger_hash));
+    let reversed: Vec<Address> = recipients.iter().rev().copied().collect();
+    assert_eq!(run(&reversed).1, ledger_hash);
+}
+
+/// Events emitted past the slot limits must fail at the same event on every run,
+/// and the events generated by the node itself must not count towards those limits.
+#[test]
+#[serial]
+fn test_generate_event_limits() {
+    let run = |config: ExecutionConfig| {
+        let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+        push_stack_element(&context, get_funded_address());
+        {
+            let mut context = context.lock();
+            for is_error in [false, true, false, true] {
+                let event =
+                    context.event_create("a node event larger than the limits".into(), is_error);
+                context.event_emit(event);
+            }
+        }
+        let failing_index = (0..10).position(|index| {
+            interface
+                .generate_event(format!("event {}", index))
+                .is_err()
+        });
+        let context = context.lock();
+        assert_eq!(
+            context.events.0.len() as u64,
+            context.contract_event_count + 4
+        );
+        (failing_index, context.contract_event_count)
+    };
+
+    let count_config = ExecutionConfig {
+        max_events_per_slot: 3,
+        ..ExecutionConfig::default()
+    };
+    assert_eq!(run(count_config.clone()), (Some(3), 3));
+    assert_eq!(run(count_config), (Some(3), 3));
+
+    // each event carries 7 bytes of data
+    let size_config = ExecutionConfig {
+        max_event_data_size_per_slot: 30,
+        ..ExecutionConfig::default()
+    };
+    assert_eq!(run(size_config.clone()), (Some(4), 4));
+    assert_eq!(run(size_config), (Some(4), 4));
+
+    assert_eq!(run(ExecutionConfig::default()), (None, 10));
+}
+
+/// Read-only and writable SC addresses created at the same slot and index must differ,
+/// and their thread must only depend on the address itself.
+#[test]
+#[serial]
+fn test_sc_address_read_only_flag() {
+    let config = ExecutionConfig::default();
+    let slot = Slot::new(1, 0);
+    let create_address = |read_only: bool| {
+        let (final_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+        let module_cache = Arc::new(RwLock::new(ModuleCache::new(
+            config.gas_costs.clone(),
+            config.max_module_cache_size,
+            config.max_compile_gas,
+        )));
+        let active_history = Arc::new(RwLock::new(ActiveHistory::default()));
+        let context = if read_only {
+            ExecutionContext::readonly(
+                config.clone(),
+                slot,
+                config.max_gas_per_block,
+                vec![],
+                final_state,
+                active_history,
+                module_cache,
+            )
+        } else {
+            ExecutionContext::active_slot(
+                config.clone(),
+                slot,
+                None,
+                final_state,
+                active_history,
+                module_cache,
+            )
+        };
+        let context = Arc::new(Mutex::new(context));
+        push_stack_element(&context, get_funded_address());
+        let mut context = context.lock();
+        context.create_new_sc_address(b"bytecode".to_vec()).unwrap()
+    };
+
+    let read_only_address = create_address(true);
+    let write_address = create_address(false);
+    assert_ne!(read_only_address, write_address);
+    assert_eq!(create_address(true), read_only_address);
+    assert_eq!(create_address(false), write_address);
+    for address in [read_only_address, write_address] {
+        let thread = address.get_thread(config.thread_count);
+        assert!(thread < config.thread_count);
+        let same_bytes = Address::from_str(&address.to_string()).unwrap();
+        assert_eq!(same_bytes.get_thread(config.thread_count), thread);
+    }
+}
+
+/// A view-only context must reject ledger writes and coin transfers but still allow reads and calls without coins.
+#[test]
+#[serial]
+fn test_view_only_context() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    let funded = get_funded_address();
+    push_stack_element(&context, funded);
+    let module = interface.create_module(b"bytecode").unwrap();
+    interface.raw_set_data(b"key", b"value").unwrap();
+    context.lock().view_only = true;
+
+    assert_eq!(interface.raw_get_data(b"key").unwrap(), b"value".to_vec());
+    assert!(interface.get_balance().unwrap() > 0);
+    assert!(interface.raw_set_data(b"key", b"other").is_err());
+    assert!(interface
+        .raw_set_data_for(&module, b"key", b"value")
+        .is_err());
+    assert!(interface.transfer_coins(&module, 1).is_err());
+    assert!(interface.create_module(b"bytecode").is_err());
+    assert!(interface.init_call(&module, 1).is_err());
+    assert_eq!(
+        interface.init_call(&module, 0).unwrap(),
+        b"bytecode".to_vec()
+    );
+    assert!(interface.raw_set_data(b"key", b"value").is_err());
+}
+
+/// Setting up a call must transfer the coins to the callee and push it on the call stack.
+#[test]
+#[serial]
+fn test_init_call_transfer() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    let funded = get_funded_address();
+    push_stack_element(&context, funded);
+    let module = interface.create_module(b"bytecode").unwrap();
+    let funded_balance = interface.get_balance().unwrap();
+
+    assert_eq!(
+        interface.init_call(&module, 1_000).unwrap(),
+        b"bytecode".to_vec()
+    );
+    assert_eq!(context.lock().stack.len(), 2);
+    assert_eq!(
+        interface.get_balance_for(&funded.to_string()).unwrap(),
+        funded_balance - 1_000
+    );
+    assert_eq!(interface.get_balance_for(&module).unwrap(), 1_000);
+}
+
+/// A call refused because of the call depth or the contract denylist must not move any coins.
+#[test]
+#[serial]
+fn test_init_call_failure_keeps_balances() {
+    let denied = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let config = ExecutionConfig {
+        max_call_depth: 1,
+        denied_contract_addresses: vec![denied].into_iter().collect(),
+        ..ExecutionConfig::default()
+    };
+    let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+    let funded = get_funded_address();
+    push_stack_element(&context, funded);
+    let module = interface.create_module(b"bytecode").unwrap();
+    let funded_balance = interface.get_balance().unwrap();
+
+    let err = interface.init_call(&denied.to_string(), 1_000).unwrap_err();
+    assert!(err.to_string().contains("denied"));
+    assert_eq!(interface.get_balance().unwrap(), funded_balance);
+    assert_eq!(interface.get_balance_for(&denied.to_string()).unwrap(), 0);
+    assert_eq!(context.lock().stack.len(), 1);
+
+    let err = interface.init_call(&module, 1_000).unwrap_err();
+    assert!(err.to_string().contains("max call depth of 1 exceeded"));
+    assert_eq!(interface.get_balance().unwrap(), funded_balance);
+    assert_eq!(interface.get_balance_for(&module).unwrap(), 0);
+    assert_eq!(context.lock().stack.len(), 1);
+}
+
+/// Executions of the same slot must draw the same unsafe random numbers,
+/// and restoring the RNG state must replay the same draws.
+#[test]
+#[serial]
+fn test_unsafe_random_determinism() {
+    let config = ExecutionConfig::default();
+    let draw = |interface: &InterfaceImpl| {
+        (0..4)
+            .map(|_| {
+                (
+                    interface.unsafe_random().unwrap(),
+                    interface.unsafe_random_f64().unwrap().to_bits(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let execute_slot = || {
+        let (final_state, _keep_file, _keep_dir) = get_sample_state().unwrap();
+        let module_cache = Arc::new(RwLock::new(ModuleCache::new(
+            config.gas_costs.clone(),
+            config.max_module_cache_size,
+            config.max_compile_gas,
+        )));
+        let context = Arc::new(Mutex::new(ExecutionContext::active_slot(
+            config.clone(),
+            Slot::new(1, 0),
+            None,
+            final_state,
+            Arc::new(RwLock::new(ActiveHistory::default())),
+            module_cache,
+        )));
+        let interface = InterfaceImpl::new(config.clone(), context.clone());
+        let rng_state = context.lock().get_unsafe_rng_state();
+        let draws = draw(&interface);
+        context.lock().set_unsafe_rng_state(rng_state);
+        assert_eq!(draw(&interface), draws);
+        draws
+    };
+    assert_eq!(execute_slot(), execute_slot());
+}
+
+/// Appending to a datastore entry must fail once the datastore of the address
+/// would exceed its size quota, and the failing write must leave the entry unchanged.
+#[test]
+#[serial]
+fn test_datastore_size_quota() {
+    let config = ExecutionConfig {
+        max_datastore_size_per_address: 100,
+        ..ExecutionConfig::default()
+    };
+    let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+    push_stack_element(&context, get_funded_address());
+
+    interface.raw_set_data(b"key", &[0u8; 10]).unwrap();
+    let mut appended = 0;
+    while interface.raw_append_data(b"key", &[1u8; 10]).is_ok() {
+        appended += 1;
+    }
+    // the 3 bytes of the key and 90 bytes of value fit, 100 bytes of value do not
+    assert_eq!(appended, 8);
+    let value = interface.raw_get_data(b"key").unwrap();
+    assert_eq!(value.len(), 90);
+
+    assert!(interface.raw_set_data(b"other", &[2u8; 10]).is_err());
+    assert!(!interface.has_data(b"other").unwrap());
+    assert_eq!(interface.raw_get_data(b"key").unwrap(), value);
+}
+
+/// The datastore size of an address must be kept up to date by the writes,
+/// and computing it from the ledger must be charged one gas unit per byte read.
+#[test]
+#[serial]
+fn test_datastore_size_tracking() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    let funded = get_funded_address();
+    push_stack_element(&context, funded);
+
+    // the datastore of the funded address is empty: computing its size is free
+    interface.raw_set_data(b"a", &[0u8; 10]).unwrap();
+    interface.raw_set_data(b"bb", &[0u8; 20]).unwrap();
+    interface.raw_set_data(b"a", &[0u8; 5]).unwrap();
+    interface.raw_append_data(b"bb", &[0u8; 10]).unwrap();
+    assert_eq!(context.lock().datastore_sizes.get(&funded), Some(&38));
+    interface.raw_delete_data(b"a").unwrap();
+    assert_eq!(context.lock().datastore_sizes.get(&funded), Some(&32));
+    assert_eq!(context.lock().interface_gas_used, 0);
+
+    // once forgotten, the size is computed again from the ledger and charged
+    context.lock().datastore_sizes.clear();
+    assert!(interface.raw_set_data(b"c", &[0u8; 1]).is_err());
+    context.lock().max_gas = 32;
+    interface.raw_set_data(b"c", &[0u8; 1]).unwrap();
+    assert_eq!(context.lock().datastore_sizes.get(&funded), Some(&34));
+    assert_eq!(context.lock().interface_gas_used, 32);
+}
+
+/// Test that the module cache counts a miss on first compilation and a hit once the module is saved
+#[test]
+#[serial]
+fn test_module_cache_stats() {
+    let config = ExecutionConfig::default();
+    let mut module_cache = ModuleCache::new(
+        config.gas_costs.clone(),
+        config.max_module_cache_size,
+        config.max_compile_gas,
+    );
+    let bytecode = include_bytes!("./wasm/event_test.wasm");
+
+    let module = module_cache
+        .get_module(bytecode, config.max_gas_per_block)
+        .unwrap();
+    let stats = module_cache.get_stats();
+    assert_eq!((stats.hits, stats.misses, stats.cached_modules), (0, 1, 0));
+
+    module_cache.save_module(bytecode, module, 0);
+    module_cache
+        .get_module(bytecode, config.max_gas_per_block)
+        .unwrap();
+    let stats = module_cache.get_stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 1, 0));
+    assert_eq!(stats.cached_modules, 1);
+}
+
+/// Append the unsigned LEB128 encoding of `value` to `bytes`
+fn push_leb128(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Build a WASM module that is cheap to run but expensive to compile:
+/// its exported `main` function returns immediately,
+/// but it also contains `function_count` functions that are never called, each with a long body
+fn get_compile_heavy_bytecode(function_count: usize, body_length: usize) -> Vec<u8> {
+    let mut bytecode = b"\0asm\x01\0\0\0".to_vec();
+    let mut push_section = |id: u8, content: Vec<u8>| {
+        bytecode.push(id);
+        push_leb128(&mut bytecode, content.len());
+        bytecode.extend(content);
+    };
+
+    // a single `() -> ()` function type, shared by every function
+    push_section(1, vec![1, 0x60, 0, 0]);
+
+    let mut functions = Vec::new();
+    push_leb128(&mut functions, function_count + 1);
+    functions.resize(functions.len() + function_count + 1, 0);
+    push_section(3, functions);
+
+    // one page of memory
+    push_section(5, vec![1, 0, 1]);
+
+    let mut exports = vec![2, 4];
+    exports.extend(b"main");
+    exports.extend([0, 0, 6]);
+    exports.extend(b"memory");
+    exports.extend([2, 0]);
+    push_section(7, exports);
+
+    // `main` only returns, the other functions push and drop a constant `body_length` times
+    let mut heavy_body = vec![0];
+    for _ in 0..body_length {
+        heavy_body.extend([0x41, 0, 0x1a]);
+    }
+    heavy_body.push(0x0b);
+    let mut code = Vec::new();
+    push_leb128(&mut code, function_count + 1);
+    code.extend([2, 0, 0x0b]);
+    for _ in 0..function_count {
+        push_leb128(&mut code, heavy_body.len());
+        code.extend(&heavy_body);
+    }
+    push_section(10, code);
+    bytecode
+}
+
+/// Test that the compilation of a module is metered against the compile limit, and not against the execution gas:
+/// a module that is cheap to run but expensive to compile is refused under a compile limit
+/// that comfortably covers the compilation of a regular smart contract,
+/// even though plenty of execution gas is available
+#[test]
+#[serial]
+fn test_module_compile_limit() {
+    let config = ExecutionConfig::default();
+    let compiles = |bytecode: &[u8], compile_limit: u64, exec_limit: u64| {
+        ModuleCache::new(
+            config.gas_costs.clone(),
+            config.max_module_cache_size,
+            compile_limit,
+        )
+        .get_module(bytecode, exec_limit)
+    };
+
+    // find the compile gas needed by a regular smart contract, which does not depend on the execution gas
+    let bytecode = include_bytes!("./wasm/event_test.wasm");
+    assert!(compiles(bytecode, config.max_compile_gas, 0).is_ok());
+    let (mut refused, mut accepted) = (0, config.max_compile_gas);
+    match compiles(bytecode, refused, config.max_gas_per_block) {
+        Err(ExecutionError::RuntimeError(msg)) => {
+            assert!(msg.contains("within the compile gas limit"))
+        }
+        _ => panic!("expected the compile gas limit to be exceeded"),
+    }
+    while accepted - refused > 1 {
+        let limit = refused + (accepted - refused) / 2;
+        if compiles(bytecode, limit, 0).is_ok() {
+            accepted = limit;
+        } else {
+            refused = limit;
+        }
+    }
+
+    // the heavy module has about a hundred times more code to compile than the regular one
+    let heavy_bytecode = get_compile_heavy_bytecode(500, 300);
+    assert!(compiles(&heavy_bytecode, u64::MAX, 0).is_ok());
+    let compile_limit = accepted * 10;
+    assert!(compiles(bytecode, compile_limit, 0).is_ok());
+    match compiles(&heavy_bytecode, compile_limit, config.max_gas_per_block) {
+        Err(ExecutionError::RuntimeError(msg)) => {
+            assert!(msg.contains("within the compile gas limit"))
+        }
+        _ => panic!("expected the compile gas limit to be exceeded"),
+    }
+}
+
+#[cfg(feature = "context_lock_metrics")]
+#[test]
+#[serial]
+fn test_context_lock_hold_time() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    let address = get_funded_address();
+    push_stack_element(&context, address);
+    assert_eq!(
+        context.lock().context_lock_hold_time,
+        std::time::Duration::ZERO
+    );
+    for _ in 0..1_000 {
+        interface.get_balance().unwrap();
+        interface.get_current_period().unwrap();
+        interface.get_current_thread().unwrap();
+    }
+    assert!(context.lock().context_lock_hold_time > std::time::Duration::ZERO);
+}
+
+#[test]
+#[serial]
+fn test_init_call_denied_contract() {
+    let denied = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let config = ExecutionConfig {
+        denied_contract_addresses: vec![denied].into_iter().collect(),
+        ..ExecutionConfig::default()
+    };
+    let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+    push_stack_element(&context, get_funded_address());
+    let err = interface.init_call(&denied.to_string(), 0).unwrap_err();
+    assert!(err.to_string().contains("denied"));
+    assert_eq!(context.lock().stack.len(), 1);
+}
+
+/// A contract recursively calling itself must fail deterministically once the max call depth is reached.
+#[test]
+#[serial]
+fn test_init_call_max_depth() {
+    let config = ExecutionConfig {
+        max_call_depth: 5,
+        ..ExecutionConfig::default()
+    };
+    let (interface, context, _keep_file, _keep_dir) = get_sample_interface(config);
+    push_stack_element(&context, get_funded_address());
+    let module = interface.create_module(b"bytecode").unwrap();
+
+    for depth in 2..=5 {
+        interface.init_call(&module, 0).unwrap();
+        assert_eq!(context.lock().stack.len(), depth);
+    }
+    let err = interface.init_call(&module, 0).unwrap_err();
+    assert!(err.to_string().contains("max call depth of 5 exceeded"));
+    assert_eq!(context.lock().stack.len(), 5);
+
+    // returning from a call frees room for exactly one nested call again
+    interface.finish_call().unwrap();
+    interface.init_call(&module, 0).unwrap();
+    assert!(interface.init_call(&module, 0).is_err());
+    assert_eq!(context.lock().stack.len(), 5);
+}
+
+/// Every mutating ABI must fail when the current call is read-only, and calls it makes must be read-only too.
+#[test]
+#[serial]
+fn test_read_only_stack_element() {
+    let (interface, context, _keep_file, _keep_dir) =
+        get_sample_interface(ExecutionConfig::default());
+    let funded = get_funded_address();
+    push_stack_element(&context, funded);
+    let module = interface.create_module(b"bytecode").unwrap();
+    interface.raw_set_data(b"key", b"value").unwrap();
+    context.lock().stack.last_mut().unwrap().is_read_only = true;
+
+    assert!(interface.raw_set_data(b"key", b"other").is_err());
+    assert!(interface.transfer_coins(&module, 1).is_err());
+    assert!(interface
+        .send_message(
+            &module,
+            "handler",
+            (1, 0),
+            (10, 0),
+            1_000,
+            100,
+            1_000,
+            b"data",
+            None,
+        )
+        .is_err());
+    assert!(interface.create_module(b"bytecode").is_err());
+    assert!(interface.raw_set_bytecode(b"bytecode").is_err());
+    assert_eq!(interface.raw_get_data(b"key").unwrap(), b"value".to_vec());
+
+    // calls without coins are allowed, and the callee is read-only as well
+    assert!(interface.init_call(&module, 1).is_err());
+    interface.init_call(&module, 0).unwrap();
+    assert!(context.lock().stack.last().unwrap().is_read_only);
+    assert!(interface.raw_set_data(b"key", b"value").is_err());
+}
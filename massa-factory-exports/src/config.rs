@@ -19,6 +19,15 @@ pub struct FactoryConfig {
     /// initial delay before starting production, to avoid double-production on node restart
     pub initial_delay: MassaTime,
 
+    /// minimal number of established connections before starting production, 0 disables the check
+    pub min_connections: usize,
+
+    /// maximal delay applied to the endorsement production instant to spread network load, capped to t0/8, 0 disables it
+    pub endorsement_jitter: MassaTime,
+
+    /// delay after the timestamp of a slot beyond which endorsements are no longer produced for that slot
+    pub endorsement_lateness_cutoff: MassaTime,
+
     /// maximal block size in bytes
     pub max_block_size: u64,
 
@@ -11,6 +11,9 @@ impl Default for FactoryConfig {
             genesis_timestamp: MassaTime::now().expect("failed to get current time"),
             t0: T0,
             initial_delay: MassaTime::from(0),
+            min_connections: 0,
+            endorsement_jitter: MassaTime::from(0),
+            endorsement_lateness_cutoff: T0,
             max_block_size: MAX_BLOCK_SIZE as u64,
             max_block_gas: MAX_GAS_PER_BLOCK,
         }
@@ -1,7 +1,7 @@
-use massa_hash::Hash;
 use massa_models::{
     block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer},
+    operation::compute_operations_merkle_root,
     secure_share::SecureShareContent,
     slot::Slot,
 };
@@ -13,7 +13,7 @@ pub fn create_empty_block(keypair: &KeyPair, slot: &Slot) -> SecureShareBlock {
         BlockHeader {
             slot: *slot,
             parents: Vec::new(),
-            operation_merkle_root: Hash::compute_from(&Vec::new()),
+            operation_merkle_root: compute_operations_merkle_root(&[]),
             endorsements: Vec::new(),
         },
         BlockHeaderSerializer::new(),
@@ -1,12 +1,12 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_factory_exports::{FactoryChannels, FactoryConfig};
-use massa_hash::Hash;
 use massa_models::{
     block::{Block, BlockSerializer},
     block_header::{BlockHeader, BlockHeaderSerializer, SecuredHeader},
     block_id::BlockId,
     endorsement::SecureShareEndorsement,
+    operation::compute_operations_merkle_root,
     prehash::PreHashSet,
     secure_share::SecureShareContent,
     slot::Slot,
@@ -20,7 +20,7 @@ use std::{
     thread,
     time::Instant,
 };
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 /// Structure gathering all elements needed by the factory thread
 pub(crate) struct BlockFactoryWorker {
@@ -118,6 +118,33 @@ impl BlockFactoryWorker {
         }
     }
 
+    /// Wait until the node has at least `min_connections` established connections, or a stop signal.
+    /// The connection count is polled from protocol every period.
+    ///
+    /// # Return value
+    /// Returns `true` if enough connections are established, otherwise `false` if there was an interruption.
+    fn wait_for_min_connections(&mut self) -> bool {
+        if self.cfg.min_connections == 0 {
+            return true;
+        }
+        loop {
+            match self.channels.protocol.get_active_node_count() {
+                Ok(count) if count >= self.cfg.min_connections => return true,
+                Ok(count) => debug!(
+                    "block factory waiting for {} connections before producing, {} established",
+                    self.cfg.min_connections, count
+                ),
+                Err(err) => warn!(
+                    "block factory could not get the connection count from protocol: {}",
+                    err
+                ),
+            }
+            if !self.interruptible_wait_until(Instant::now() + self.cfg.t0.to_duration()) {
+                return false;
+            }
+        }
+    }
+
     /// Process a slot: produce a block at that slot if one of the managed keys is drawn.
     fn process_slot(&mut self, slot: Slot) {
         // get block producer address for that slot
@@ -191,12 +218,7 @@ impl BlockFactoryWorker {
         // gather operations and compute global operations hash
         let (op_ids, op_storage) = self.channels.pool.get_block_operations(&slot);
         block_storage.extend(op_storage);
-        let global_operations_hash = Hash::compute_from(
-            &op_ids
-                .iter()
-                .flat_map(|op_id| *op_id.to_bytes())
-                .collect::<Vec<u8>>(),
-        );
+        let global_operations_hash = compute_operations_merkle_root(&op_ids);
 
         // create header
         let header: SecuredHeader = BlockHeader::new_verifiable::<BlockHeaderSerializer, BlockId>(
@@ -239,6 +261,11 @@ impl BlockFactoryWorker {
 
     /// main run loop of the block creator thread
     fn run(&mut self) {
+        // do not produce on a stale view of the network
+        if !self.wait_for_min_connections() {
+            return;
+        }
+
         let mut prev_slot = None;
         loop {
             // get next slot
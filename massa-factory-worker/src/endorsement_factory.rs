@@ -1,14 +1,15 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_factory_exports::{FactoryChannels, FactoryConfig};
+use massa_hash::Hash;
 use massa_models::{
+    address::Address,
     block_id::BlockId,
     endorsement::{Endorsement, EndorsementSerializer, SecureShareEndorsement},
     secure_share::SecureShareContent,
     slot::Slot,
     timeslots::{get_block_slot_timestamp, get_closest_slot_to_timestamp},
 };
-use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use massa_wallet::Wallet;
 use parking_lot::RwLock;
@@ -25,7 +26,7 @@ pub(crate) struct EndorsementFactoryWorker {
     wallet: Arc<RwLock<Wallet>>,
     channels: FactoryChannels,
     factory_receiver: mpsc::Receiver<()>,
-    half_t0: MassaTime,
+    jitter_seed: Vec<u8>,
     endorsement_serializer: EndorsementSerializer,
 }
 
@@ -41,11 +42,19 @@ impl EndorsementFactoryWorker {
         thread::Builder::new()
             .name("endorsement-factory".into())
             .spawn(|| {
+                // seed the jitter with the addresses of the wallet so that it is deterministic for a given node
+                let mut wallet_addrs: Vec<_> = wallet
+                    .read()
+                    .get_wallet_address_list()
+                    .into_iter()
+                    .collect();
+                wallet_addrs.sort_unstable();
+                let jitter_seed = wallet_addrs
+                    .iter()
+                    .flat_map(|addr| addr.to_bytes().to_vec())
+                    .collect();
                 let mut this = Self {
-                    half_t0: cfg
-                        .t0
-                        .checked_div_u64(2)
-                        .expect("could not compute half_t0"),
+                    jitter_seed,
                     cfg,
                     wallet,
                     channels,
@@ -93,17 +102,11 @@ impl EndorsementFactoryWorker {
             next_slot = Slot::new(1, 0);
         }
 
-        // get the timestamp of the target slot
-        let next_instant = get_block_slot_timestamp(
-            self.cfg.thread_count,
-            self.cfg.t0,
-            self.cfg.genesis_timestamp,
-            next_slot,
-        )
-        .expect("could not get block slot timestamp")
-        .saturating_sub(self.half_t0)
-        .estimate_instant()
-        .expect("could not estimate block slot instant");
+        // get the production instant of the target slot
+        let next_instant =
+            get_endorsement_production_timestamp(&self.cfg, &self.jitter_seed, next_slot)
+                .estimate_instant()
+                .expect("could not estimate block slot instant");
 
         (next_slot, next_instant)
     }
@@ -123,11 +126,58 @@ impl EndorsementFactoryWorker {
         }
     }
 
+    /// Wait until the node has at least `min_connections` established connections, or a stop signal.
+    /// The connection count is polled from protocol every period.
+    ///
+    /// # Return value
+    /// Returns `true` if enough connections are established, otherwise `false` if there was an interruption.
+    fn wait_for_min_connections(&mut self) -> bool {
+        if self.cfg.min_connections == 0 {
+            return true;
+        }
+        loop {
+            match self.channels.protocol.get_active_node_count() {
+                Ok(count) if count >= self.cfg.min_connections => return true,
+                Ok(count) => debug!(
+                    "endorsement factory waiting for {} connections before producing, {} established",
+                    self.cfg.min_connections, count
+                ),
+                Err(err) => warn!(
+                    "endorsement factory could not get the connection count from protocol: {}",
+                    err
+                ),
+            }
+            if !self.interruptible_wait_until(Instant::now() + self.cfg.t0.to_duration()) {
+                return false;
+            }
+        }
+    }
+
     /// Process a slot: produce an endorsement at that slot if one of the managed keys is drawn.
     fn process_slot(&mut self, slot: Slot) {
-        // get endorsement producer addresses for that slot
-        let producer_addrs = match self.channels.selector.get_selection(slot) {
-            Ok(sel) => sel.endorsements,
+        // skip production if we woke up too late for that slot
+        let now = MassaTime::now().expect("could not get current time");
+        if is_past_endorsement_cutoff(&self.cfg, slot, now) {
+            warn!(
+                "endorsement factory skipped slot {}: production cutoff elapsed",
+                slot
+            );
+            return;
+        }
+
+        // get the endorsement indices drawn for the addresses managed by our wallet
+        let managed_addrs: Vec<Address> = self
+            .wallet
+            .read()
+            .get_wallet_address_list()
+            .into_iter()
+            .collect();
+        let drawn_indices = match self
+            .channels
+            .selector
+            .get_endorsement_indices_for(slot, &managed_addrs)
+        {
+            Ok(indices) => indices,
             Err(err) => {
                 warn!(
                     "endorsement factory could not get selector draws for slot {}: {}",
@@ -137,26 +187,8 @@ impl EndorsementFactoryWorker {
             }
         };
 
-        // get creators if they are managed by our wallet
-        let mut producers_indices: Vec<(KeyPair, usize)> = Vec::new();
-        {
-            let wallet = self.wallet.read();
-            for (index, producer_addr) in producer_addrs.into_iter().enumerate() {
-                // check if the block producer address is handled by the wallet
-                let producer_keypair =
-                    if let Some(kp) = wallet.find_associated_keypair(&producer_addr) {
-                        // the selected block producer is managed locally => continue to attempt endorsement production
-                        kp.clone()
-                    } else {
-                        // the selected block producer is not managed locally => continue
-                        continue;
-                    };
-                producers_indices.push((producer_keypair, index));
-            }
-        }
-
         // quit if there is nothing to produce
-        if producers_indices.is_empty() {
+        if drawn_indices.is_empty() {
             return;
         }
 
@@ -167,27 +199,40 @@ impl EndorsementFactoryWorker {
             .get_latest_blockclique_block_at_slot(slot);
 
         // produce endorsements
-        let mut endorsements: Vec<SecureShareEndorsement> =
-            Vec::with_capacity(producers_indices.len());
-        for (keypair, index) in producers_indices {
-            let endorsement = Endorsement::new_verifiable(
-                Endorsement {
-                    slot,
-                    index: index as u32,
-                    endorsed_block,
-                },
-                self.endorsement_serializer.clone(),
-                &keypair,
-            )
-            .expect("could not create endorsement");
-
-            // log endorsement creation
-            debug!(
-                "endorsement {} created at slot {} by address {}",
-                endorsement.id, endorsement.content.slot, endorsement.content_creator_address
-            );
+        let mut endorsements: Vec<SecureShareEndorsement> = Vec::with_capacity(drawn_indices.len());
+        {
+            let wallet = self.wallet.read();
+            for (index, producer_addr) in drawn_indices {
+                let keypair = if let Some(kp) = wallet.find_associated_keypair(&producer_addr) {
+                    kp
+                } else {
+                    // the wallet may have changed since the addresses were listed
+                    continue;
+                };
+                let endorsement = Endorsement::new_verifiable(
+                    Endorsement {
+                        slot,
+                        index,
+                        endorsed_block,
+                    },
+                    self.endorsement_serializer.clone(),
+                    keypair,
+                )
+                .expect("could not create endorsement");
+
+                // log endorsement creation
+                debug!(
+                    "endorsement {} created at slot {} by address {}",
+                    endorsement.id, endorsement.content.slot, endorsement.content_creator_address
+                );
+
+                endorsements.push(endorsement);
+            }
+        }
 
-            endorsements.push(endorsement);
+        // quit if the managed keys were removed in the meantime
+        if endorsements.is_empty() {
+            return;
         }
 
         // store endorsements
@@ -204,6 +249,11 @@ impl EndorsementFactoryWorker {
 
     /// main run loop of the endorsement creator thread
     fn run(&mut self) {
+        // do not produce on a stale view of the network
+        if !self.wait_for_min_connections() {
+            return;
+        }
+
         let mut prev_slot = None;
         loop {
             // get next slot
@@ -222,3 +272,46 @@ impl EndorsementFactoryWorker {
         }
     }
 }
+
+/// Returns `true` if `now` is past the endorsement production cutoff of `slot`,
+/// that is to say the slot timestamp plus the configured lateness cutoff.
+pub(crate) fn is_past_endorsement_cutoff(cfg: &FactoryConfig, slot: Slot, now: MassaTime) -> bool {
+    let cutoff = get_block_slot_timestamp(cfg.thread_count, cfg.t0, cfg.genesis_timestamp, slot)
+        .expect("could not get block slot timestamp")
+        .saturating_add(cfg.endorsement_lateness_cutoff);
+    now > cutoff
+}
+
+/// Returns the timestamp at which the endorsements of `slot` are produced:
+/// half a period before the slot timestamp, delayed by a pseudo-random amount to spread network load.
+///
+/// The delay is at most `endorsement_jitter`, capped to an eighth of a period,
+/// so that endorsements are always produced after the endorsed block and well before the slot.
+/// It is derived from the slot and `jitter_seed` so that it is deterministic for a given node.
+pub(crate) fn get_endorsement_production_timestamp(
+    cfg: &FactoryConfig,
+    jitter_seed: &[u8],
+    slot: Slot,
+) -> MassaTime {
+    let half_t0 = cfg
+        .t0
+        .checked_div_u64(2)
+        .expect("could not compute half_t0");
+    let timestamp = get_block_slot_timestamp(cfg.thread_count, cfg.t0, cfg.genesis_timestamp, slot)
+        .expect("could not get block slot timestamp")
+        .saturating_sub(half_t0);
+    let max_jitter = cfg
+        .t0
+        .checked_div_u64(8)
+        .expect("could not compute max jitter");
+    let jitter = std::cmp::min(cfg.endorsement_jitter, max_jitter).to_millis();
+    if jitter == 0 {
+        return timestamp;
+    }
+    let mut data = jitter_seed.to_vec();
+    data.extend(slot.to_bytes_key());
+    let hash = Hash::compute_from(&data).into_bytes();
+    let delay = u64::from_be_bytes(hash[..8].try_into().expect("hash is too short"))
+        % jitter.saturating_add(1);
+    timestamp.saturating_add(MassaTime::from_millis(delay))
+}
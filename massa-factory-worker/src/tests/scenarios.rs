@@ -1,11 +1,33 @@
 use super::TestFactory;
+use crate::{
+    endorsement_factory::{get_endorsement_production_timestamp, is_past_endorsement_cutoff},
+    start_factory,
+};
+use massa_consensus_exports::test_exports::MockConsensusController;
+use massa_factory_exports::{FactoryChannels, FactoryConfig};
 use massa_models::{
+    address::Address,
     amount::Amount,
+    config::ENDORSEMENT_COUNT,
     operation::{Operation, OperationSerializer, OperationType},
+    prehash::PreHashMap,
     secure_share::SecureShareContent,
+    slot::Slot,
 };
+use massa_pool_exports::test_exports::MockPoolController;
+use massa_pos_exports::test_exports::MockSelectorController;
+use massa_protocol_exports::{ProtocolCommand, ProtocolCommandSender};
 use massa_signature::KeyPair;
-use std::str::FromStr;
+use massa_storage::Storage;
+use massa_time::MassaTime;
+use massa_wallet::test_exports::create_test_wallet;
+use parking_lot::RwLock;
+use std::{
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
 
 /// Creates a basic empty block with the factory.
 #[test]
@@ -63,3 +85,149 @@ fn basic_creation_with_multiple_operations() {
     }
     assert_eq!(block.content.operations.len(), 2);
 }
+
+/// Creates endorsements with a wallet holding the key drawn for every endorsement slot.
+#[test]
+fn basic_endorsement_creation() {
+    let keypair = KeyPair::generate();
+    let mut test_factory = TestFactory::new(&keypair);
+    let storage = test_factory.get_next_created_endorsements();
+
+    let address = Address::from_public_key(&keypair.get_public_key());
+    let endorsement_ids = storage.get_endorsement_refs();
+    assert_eq!(endorsement_ids.len(), ENDORSEMENT_COUNT as usize);
+    let endorsements = storage.read_endorsements();
+    for endorsement_id in endorsement_ids {
+        let endorsement = endorsements.get(endorsement_id).unwrap();
+        assert_eq!(endorsement.content_creator_address, address);
+    }
+}
+
+/// Simulates a late wake-up of the endorsement factory: production is skipped once the cutoff has elapsed.
+#[test]
+fn endorsement_skipped_after_lateness_cutoff() {
+    let cfg = FactoryConfig {
+        genesis_timestamp: MassaTime::from_millis(0),
+        t0: MassaTime::from_millis(16_000),
+        endorsement_lateness_cutoff: MassaTime::from_millis(1_000),
+        ..FactoryConfig::default()
+    };
+    let slot = Slot::new(10, 0);
+    let slot_timestamp = MassaTime::from_millis(160_000);
+    // on time: woke up half a period before the slot
+    assert!(!is_past_endorsement_cutoff(
+        &cfg,
+        slot,
+        MassaTime::from_millis(152_000)
+    ));
+    // slightly late but within the cutoff
+    assert!(!is_past_endorsement_cutoff(
+        &cfg,
+        slot,
+        slot_timestamp.saturating_add(cfg.endorsement_lateness_cutoff)
+    ));
+    // woke up after the cutoff
+    assert!(is_past_endorsement_cutoff(
+        &cfg,
+        slot,
+        MassaTime::from_millis(161_001)
+    ));
+}
+
+/// Checks that the jittered endorsement production timestamps stay between half a period before the slot
+/// and an eighth of a period later, even with a configured jitter larger than that.
+#[test]
+fn endorsement_jitter_bounds() {
+    let cfg = FactoryConfig {
+        genesis_timestamp: MassaTime::from_millis(0),
+        t0: MassaTime::from_millis(16_000),
+        endorsement_jitter: MassaTime::from_millis(16_000),
+        ..FactoryConfig::default()
+    };
+    let seed = Address::from_public_key(&KeyPair::generate().get_public_key())
+        .to_bytes()
+        .to_vec();
+    let mut delays = std::collections::BTreeSet::new();
+    for period in 1..100 {
+        let slot = Slot::new(period, 0);
+        let earliest = MassaTime::from_millis(period * 16_000 - 8_000);
+        let timestamp = get_endorsement_production_timestamp(&cfg, &seed, slot);
+        assert!(timestamp >= earliest);
+        assert!(timestamp <= earliest.saturating_add(MassaTime::from_millis(2_000)));
+        assert_eq!(
+            timestamp,
+            get_endorsement_production_timestamp(&cfg, &seed, slot)
+        );
+        delays.insert(timestamp.saturating_sub(earliest));
+    }
+    assert!(delays.len() > 1, "the production instants are not spread");
+
+    // without jitter, endorsements are produced half a period before the slot
+    let cfg = FactoryConfig {
+        endorsement_jitter: MassaTime::from_millis(0),
+        ..cfg
+    };
+    assert_eq!(
+        get_endorsement_production_timestamp(&cfg, &seed, Slot::new(10, 0)),
+        MassaTime::from_millis(152_000)
+    );
+}
+
+/// Starts the factory without any established connection: neither the block nor the endorsement
+/// factory must start producing while the minimal connection count is not reached.
+#[test]
+fn no_production_without_connections() {
+    let keypair = KeyPair::generate();
+    let mut accounts = PreHashMap::default();
+    accounts.insert(Address::from_public_key(&keypair.get_public_key()), keypair);
+    let (selector_controller, selector_receiver) = MockSelectorController::new_with_receiver();
+    let (consensus_controller, _consensus_event_receiver) =
+        MockConsensusController::new_with_receiver();
+    let (pool_controller, _pool_receiver) = MockPoolController::new_with_receiver();
+    let (protocol_command_tx, mut protocol_command_rx) = mpsc::channel::<ProtocolCommand>(256);
+    let mut cfg = FactoryConfig {
+        t0: MassaTime::from_millis(400),
+        min_connections: 1,
+        ..FactoryConfig::default()
+    };
+    cfg.genesis_timestamp = cfg.genesis_timestamp.checked_sub(cfg.t0).unwrap();
+    let mut factory_manager = start_factory(
+        cfg.clone(),
+        Arc::new(RwLock::new(create_test_wallet(Some(accounts)))),
+        FactoryChannels {
+            selector: selector_controller,
+            consensus: consensus_controller,
+            pool: pool_controller,
+            protocol: ProtocolCommandSender(protocol_command_tx),
+            storage: Storage::create_root(),
+        },
+    );
+
+    // answer every connection count query with zero for a few periods
+    let mut queries = 0;
+    let deadline = Instant::now() + cfg.t0.to_duration() * 4;
+    while Instant::now() < deadline {
+        while let Ok(command) = protocol_command_rx.try_recv() {
+            match command {
+                ProtocolCommand::GetActiveNodeCount(response_tx) => {
+                    queries += 1;
+                    let _ = response_tx.send(0);
+                }
+                _ => panic!("unexpected protocol command before production started"),
+            }
+        }
+        // production starts by asking the selector for draws
+        assert!(
+            selector_receiver
+                .recv_timeout(Duration::from_millis(10))
+                .is_err(),
+            "the factory produced without any established connection"
+        );
+    }
+    // both factories keep polling the connection count
+    assert!(queries >= 2);
+
+    // fail any pending query so that the workers notice the stop signal
+    drop(protocol_command_rx);
+    factory_manager.stop();
+}
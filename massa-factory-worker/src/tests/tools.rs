@@ -3,9 +3,12 @@ use massa_consensus_exports::test_exports::{
 };
 use parking_lot::RwLock;
 use std::{
-    sync::{mpsc::Receiver, Arc},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc,
+    },
     thread::sleep,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use massa_factory_exports::{
@@ -219,10 +222,76 @@ impl TestFactory {
             })
             .unwrap()
     }
+
+    /// This functions answers all the asks of the factory with mocks until the endorsement factory
+    /// sends the endorsements it created to the pool.
+    /// All the endorsement draws are given to the factory keypair,
+    /// and the block draws to an address outside of the wallet so that no block is produced meanwhile.
+    ///
+    /// Returns
+    /// - `Storage`: the storage containing the created endorsements
+    pub fn get_next_created_endorsements(&mut self) -> Storage {
+        let producer_address = Address::from_public_key(&self.keypair.get_public_key());
+        let block_producer_address =
+            Address::from_public_key(&KeyPair::generate().get_public_key());
+        let genesis_blocks = self.genesis_blocks.clone();
+        let deadline = Instant::now() + self.factory_config.t0.to_duration() * 4;
+        while Instant::now() < deadline {
+            while let Ok(message) = self.selector_receiver.try_recv() {
+                match message {
+                    MockSelectorControllerMessage::GetProducer {
+                        slot: _,
+                        response_tx,
+                    } => {
+                        let _ = response_tx.send(Ok(block_producer_address));
+                    }
+                    MockSelectorControllerMessage::GetSelection {
+                        slot: _,
+                        response_tx,
+                    } => {
+                        let _ = response_tx.send(Ok(Selection {
+                            producer: block_producer_address,
+                            endorsements: vec![producer_address; ENDORSEMENT_COUNT as usize],
+                        }));
+                    }
+                    _ => panic!("unexpected message"),
+                }
+            }
+            self.consensus_event_receiver
+                .wait_command(MassaTime::from_millis(10), |command| {
+                    match command {
+                        MockConsensusControllerMessage::GetLatestBlockcliqueBlockAtSlot {
+                            slot: _,
+                            response_tx,
+                        } => {
+                            let _ = response_tx.send(genesis_blocks[0].0);
+                        }
+                        _ => {}
+                    }
+                    Some(())
+                });
+            let created = self
+                .pool_receiver
+                .wait_command(MassaTime::from_millis(10), |command| match command {
+                    MockPoolControllerMessage::AddEndorsements { endorsements } => {
+                        Some(endorsements)
+                    }
+                    _ => None,
+                });
+            if let Some(endorsements) = created {
+                return endorsements;
+            }
+        }
+        panic!("the endorsement factory did not create any endorsement");
+    }
 }
 
 impl Drop for TestFactory {
     fn drop(&mut self) {
+        // drop the mock receivers so that the workers blocked on a mock call fail and notice the stop signal
+        self.selector_receiver = mpsc::channel().1;
+        self.consensus_event_receiver = ConsensusEventReceiver(mpsc::channel().1);
+        self.pool_receiver = PoolEventReceiver(mpsc::channel().1);
         self.factory_manager.stop();
     }
 }
@@ -6,7 +6,7 @@ use massa_hash::{Hash, HashDeserializer};
 use massa_serialization::{
     DeserializeError, Deserializer, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
 };
-use massa_signature::PublicKey;
+use massa_signature::{PublicKey, HEX_PREFIX};
 use nom::error::{context, ContextError, ParseError};
 use nom::{IResult, Parser};
 use serde::{Deserialize, Serialize};
@@ -129,6 +129,9 @@ impl FromStr for Address {
     /// assert_eq!(address, res_addr);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(HEX_PREFIX) {
+            return Address::from_hex(s);
+        }
         let mut chars = s.chars();
         match chars.next() {
             Some(prefix) if prefix == ADDRESS_PREFIX => {
@@ -162,6 +165,20 @@ fn test_address_str_format() {
     assert!(address == b);
 }
 
+#[test]
+fn test_address_hex_format() {
+    use massa_signature::KeyPair;
+
+    let keypair = KeyPair::generate();
+    let address = Address::from_public_key(&keypair.get_public_key());
+    let from_bs58 = Address::from_str(&address.to_string()).unwrap();
+    let from_hex = Address::from_str(&address.to_hex()).unwrap();
+    assert_eq!(from_bs58, address);
+    assert_eq!(from_hex, address);
+    assert_eq!(from_hex.to_string(), address.to_string());
+    assert!(Address::from_str("0x1234").is_err());
+}
+
 impl PreHashed for Address {}
 
 impl Address {
@@ -174,9 +191,39 @@ impl Address {
 
     /// Computes address associated with given public key
     pub fn from_public_key(public_key: &PublicKey) -> Self {
+        #[cfg(feature = "address_cache")]
+        {
+            address_cache::get_or_compute(public_key)
+        }
+        #[cfg(not(feature = "address_cache"))]
+        {
+            Address::compute_from_public_key(public_key)
+        }
+    }
+
+    /// Hashes the public key into its address, bypassing any cache
+    fn compute_from_public_key(public_key: &PublicKey) -> Self {
         Address(Hash::compute_from(public_key.to_bytes()))
     }
 
+    /// Serializes the address as a `0x` prefixed hexadecimal string of its raw bytes.
+    /// The canonical representation remains the one given by `Display`.
+    pub fn to_hex(&self) -> String {
+        format!("{}{}", HEX_PREFIX, hex::encode(self.to_bytes()))
+    }
+
+    /// Parses an address from a hexadecimal string of its raw bytes.
+    /// The `0x` prefix is optional.
+    pub fn from_hex(s: &str) -> Result<Self, ModelsError> {
+        let s = s.strip_prefix(HEX_PREFIX).unwrap_or(s);
+        let bytes = hex::decode(s).map_err(|_| ModelsError::AddressParseError)?;
+        Ok(Address(Hash::from_bytes(
+            &bytes
+                .try_into()
+                .map_err(|_| ModelsError::AddressParseError)?,
+        )))
+    }
+
     /// ## Example
     /// ```rust
     /// # use massa_signature::{PublicKey, KeyPair, Signature};
@@ -302,3 +349,61 @@ pub struct ExecutionAddressCycleInfo {
     /// number of active rolls the address had at that cycle (if still available)
     pub active_rolls: Option<u64>,
 }
+
+/// Memoization of the public key to address derivation.
+///
+/// The cache is direct-mapped: each public key can only be stored in the slot selected
+/// by its first bytes, and a slot holds the full key it was computed from,
+/// so a hit always returns the address derived from the exact same key.
+#[cfg(feature = "address_cache")]
+mod address_cache {
+    use super::Address;
+    use massa_signature::PublicKey;
+    use std::sync::Mutex;
+
+    /// Number of cached derivations
+    const ADDRESS_CACHE_SIZE: usize = 4096;
+
+    lazy_static::lazy_static! {
+        static ref ADDRESS_CACHE: Vec<Mutex<Option<(PublicKey, Address)>>> =
+            (0..ADDRESS_CACHE_SIZE).map(|_| Mutex::new(None)).collect();
+    }
+
+    /// Returns the address of the public key, from the cache if possible
+    pub(super) fn get_or_compute(public_key: &PublicKey) -> Address {
+        let key_bytes = public_key.to_bytes();
+        let index = u16::from_le_bytes([key_bytes[0], key_bytes[1]]) as usize % ADDRESS_CACHE_SIZE;
+        let mut slot = ADDRESS_CACHE[index]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match *slot {
+            Some((cached_key, address)) if cached_key == *public_key => address,
+            _ => {
+                let address = Address::compute_from_public_key(public_key);
+                *slot = Some((*public_key, address));
+                address
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_signature::KeyPair;
+
+    #[test]
+    fn test_address_from_public_key() {
+        let keypairs: Vec<KeyPair> = (0..10).map(|_| KeyPair::generate()).collect();
+        // derive each address twice so that cached derivations are checked too
+        for _ in 0..2 {
+            for keypair in &keypairs {
+                let public_key = keypair.get_public_key();
+                assert_eq!(
+                    Address::from_public_key(&public_key),
+                    Address(Hash::compute_from(public_key.to_bytes()))
+                );
+            }
+        }
+    }
+}
@@ -14,6 +14,12 @@ use std::str::FromStr;
 /// decimal factor for the internal representation
 pub const AMOUNT_DECIMAL_FACTOR: u64 = 1_000_000_000;
 
+/// number of decimal places of the internal representation (number of zeros of `AMOUNT_DECIMAL_FACTOR`)
+const AMOUNT_DECIMAL_PLACES: usize = 9;
+
+/// maximal absolute value of the exponent of an amount in scientific notation (maximal scale of a `Decimal`)
+const MAX_AMOUNT_EXPONENT: u32 = 28;
+
 /// A structure representing a decimal Amount of coins with safe operations
 /// this allows ensuring that there is never an uncontrolled overflow or precision loss
 /// while providing a convenient decimal interface for users
@@ -153,6 +159,197 @@ impl Amount {
     pub fn checked_div_u64(self, factor: u64) -> Option<Self> {
         self.0.checked_div(factor).map(Amount)
     }
+
+    /// safely compute a share of self expressed in basis points (1/10000th),
+    /// rounding down and returning None if the result does not fit in an Amount.
+    /// The intermediate product is computed on 128 bits so that it cannot overflow.
+    /// ```
+    /// # use massa_models::amount::Amount;
+    /// # use std::str::FromStr;
+    /// let amount = Amount::from_str("42").unwrap();
+    /// assert_eq!(amount.checked_percentage(0).unwrap(), Amount::zero());
+    /// assert_eq!(amount.checked_percentage(10000).unwrap(), amount);
+    /// assert_eq!(amount.checked_percentage(250).unwrap(), Amount::from_str("1.05").unwrap());
+    /// // the raw product would overflow a u64 but the share does not
+    /// assert_eq!(Amount::MAX.checked_percentage(5000).unwrap(), Amount::from_raw(u64::MAX / 2));
+    /// assert_eq!(Amount::MAX.checked_percentage(10001), None);
+    /// ```
+    pub fn checked_percentage(self, basis_points: u16) -> Option<Self> {
+        let share = (self.0 as u128) * (basis_points as u128) / 10_000;
+        u64::try_from(share).ok().map(Amount)
+    }
+
+    /// display an Amount in decimal string form rounded to `decimals` decimal places,
+    /// with midpoints rounded away from zero.
+    /// The underlying raw value is left untouched.
+    /// ```
+    /// # use massa_models::amount::Amount;
+    /// # use std::str::FromStr;
+    /// let amount = Amount::from_str("11.111").unwrap();
+    /// assert_eq!(amount.to_string_with_decimals(2), "11.11");
+    /// assert_eq!(amount.to_string_with_decimals(5), "11.11100");
+    /// assert_eq!(amount.to_string_with_decimals(12), "11.111000000000");
+    /// // rounding carries over to the integer part
+    /// let amount = Amount::from_str("1.99995").unwrap();
+    /// assert_eq!(amount.to_string_with_decimals(4), "2.0000");
+    /// assert_eq!(amount.to_string_with_decimals(5), "1.99995");
+    /// let amount = Amount::from_str("9.999999999").unwrap();
+    /// assert_eq!(amount.to_string_with_decimals(0), "10");
+    /// // below the midpoint
+    /// let amount = Amount::from_str("0.00004").unwrap();
+    /// assert_eq!(amount.to_string_with_decimals(4), "0.0000");
+    /// assert_eq!(Amount::MAX.to_string_with_decimals(2), "18446744073.71");
+    /// // more decimal places than the internal representation are padded with zeros
+    /// let amount = Amount::from_str("1.5").unwrap();
+    /// assert_eq!(amount.to_string_with_decimals(40), format!("1.5{}", "0".repeat(39)));
+    /// ```
+    pub fn to_string_with_decimals(&self, decimals: usize) -> String {
+        if decimals >= AMOUNT_DECIMAL_PLACES {
+            return format!(
+                "{}.{:0places$}{}",
+                self.0 / AMOUNT_DECIMAL_FACTOR,
+                self.0 % AMOUNT_DECIMAL_FACTOR,
+                "0".repeat(decimals - AMOUNT_DECIMAL_PLACES),
+                places = AMOUNT_DECIMAL_PLACES
+            );
+        }
+        // number of units of 10^-decimals, rounded with midpoints away from zero
+        let scale = 10u64.pow((AMOUNT_DECIMAL_PLACES - decimals) as u32);
+        let units = self.0 / scale + u64::from(self.0 % scale >= scale / 2);
+        if decimals == 0 {
+            return units.to_string();
+        }
+        let decimal_factor = 10u64.pow(decimals as u32);
+        format!(
+            "{}.{:0decimals$}",
+            units / decimal_factor,
+            units % decimal_factor,
+            decimals = decimals
+        )
+    }
+
+    /// display an Amount in decimal string form rounded to at most `decimals` decimal places,
+    /// with midpoints rounded up (half-up), and trailing fractional zeros trimmed.
+    /// Use `to_string_with_decimals` to get a fixed number of decimal places instead.
+    /// ```
+    /// # use massa_models::amount::Amount;
+    /// # use std::str::FromStr;
+    /// let amount = Amount::from_str("11.125").unwrap();
+    /// assert_eq!(amount.to_string_with_precision(2), "11.13");
+    /// assert_eq!(amount.to_string_with_precision(4), "11.125");
+    /// // just below the midpoint
+    /// let amount = Amount::from_str("11.124999999").unwrap();
+    /// assert_eq!(amount.to_string_with_precision(2), "11.12");
+    /// // trailing zeros are trimmed, including when rounding carries over
+    /// let amount = Amount::from_str("1.996").unwrap();
+    /// assert_eq!(amount.to_string_with_precision(2), "2");
+    /// assert_eq!(Amount::from_str("10.5").unwrap().to_string_with_precision(0), "11");
+    /// assert_eq!(Amount::from_str("10.10").unwrap().to_string_with_precision(4), "10.1");
+    /// assert_eq!(Amount::zero().to_string_with_precision(2), "0");
+    /// assert_eq!(Amount::MAX.to_string_with_precision(9), Amount::MAX.to_string());
+    /// ```
+    pub fn to_string_with_precision(&self, decimals: u8) -> String {
+        let res = self.to_string_with_decimals(decimals.into());
+        match res.contains('.') {
+            true => res.trim_end_matches('0').trim_end_matches('.').to_string(),
+            false => res,
+        }
+    }
+
+    /// build an Amount from a human-written decimal string (like "1 000.50" or "1,000.50")
+    /// Whitespace, underscores and commas are accepted as thousands separators in the integer part only,
+    /// as long as they delimit groups of three digits. The period is the only decimal separator,
+    /// and the fractional part must only contain digits.
+    /// Inputs where a comma could be a decimal separator are rejected as ambiguous.
+    /// Only digits and separators are accepted: exponents are not.
+    ///
+    /// ```
+    /// # use massa_models::amount::Amount;
+    /// # use std::str::FromStr;
+    /// let expected = Amount::from_str("1000.5").unwrap();
+    /// assert_eq!(Amount::from_human_str("1000.5").unwrap(), expected);
+    /// assert_eq!(Amount::from_human_str(" 1 000.50 ").unwrap(), expected);
+    /// assert_eq!(Amount::from_human_str("1,000.50").unwrap(), expected);
+    /// assert_eq!(Amount::from_human_str("1_000.50").unwrap(), expected);
+    /// assert_eq!(Amount::from_human_str("1\u{a0}000.50").unwrap(), expected);
+    /// assert_eq!(
+    ///     Amount::from_human_str("12,345,678").unwrap(),
+    ///     Amount::from_str("12345678").unwrap()
+    /// );
+    /// // a comma could be a decimal separator here
+    /// assert!(Amount::from_human_str("1,5").is_err());
+    /// assert!(Amount::from_human_str("1.000,50").is_err());
+    /// assert!(Amount::from_human_str("1,00.5").is_err());
+    /// assert!(Amount::from_human_str("1.000.5").is_err());
+    /// assert!(Amount::from_human_str("1,,000").is_err());
+    /// assert!(Amount::from_human_str("-1,000").is_err());
+    /// assert!(Amount::from_human_str("1,0e3").is_err());
+    /// assert!(Amount::from_human_str("1e3").is_err());
+    /// // separators are not accepted in the fractional part
+    /// assert!(Amount::from_human_str("1.000 5").is_err());
+    /// assert!(Amount::from_human_str("1.000_5").is_err());
+    /// assert!(Amount::from_human_str("1,000.000,5").is_err());
+    /// // groups must be made of exactly three digits
+    /// assert!(Amount::from_human_str("1000 000").is_err());
+    /// assert!(Amount::from_human_str("1 00").is_err());
+    /// assert!(Amount::from_human_str("1 0000").is_err());
+    /// assert!(Amount::from_human_str("1 000 .5").is_err());
+    /// assert!(Amount::from_human_str(",000").is_err());
+    /// ```
+    pub fn from_human_str(str_amount: &str) -> Result<Self, ModelsError> {
+        let is_separator = |c: char| c.is_whitespace() || c == ',' || c == '_';
+        let str_amount = str_amount.trim();
+        let (int_part, fract_part) = match str_amount.split_once('.') {
+            Some((int_part, fract_part)) => (int_part, Some(fract_part)),
+            None => (str_amount, None),
+        };
+        if !int_part
+            .chars()
+            .all(|c| c.is_ascii_digit() || is_separator(c))
+        {
+            return Err(ModelsError::AmountParseError(format!(
+                "invalid amount {}: only digits, thousands separators and a decimal period are accepted",
+                str_amount
+            )));
+        }
+        if let Some(fract_part) = fract_part {
+            if fract_part.contains(['.', ',']) {
+                return Err(ModelsError::AmountParseError(format!(
+                    "ambiguous amount {}: only a single period can be used as decimal separator",
+                    str_amount
+                )));
+            }
+            if !fract_part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(ModelsError::AmountParseError(format!(
+                    "invalid amount {}: the fractional part can only contain digits",
+                    str_amount
+                )));
+            }
+        }
+        let mut normalized = String::with_capacity(str_amount.len());
+        if int_part.contains(is_separator) {
+            for (index, group) in int_part.split(is_separator).enumerate() {
+                let group_is_valid = match index {
+                    0 => (1..=3).contains(&group.len()),
+                    _ => group.len() == 3,
+                };
+                if !group_is_valid {
+                    return Err(ModelsError::AmountParseError(format!(
+                        "ambiguous amount {}: thousands separators must delimit groups of three digits",
+                        str_amount
+                    )));
+                }
+                normalized.push_str(group);
+            }
+        } else {
+            normalized.push_str(int_part);
+        }
+        if let Some(fract_part) = fract_part {
+            normalized.push('.');
+            normalized.push_str(fract_part);
+        }
+        Amount::from_str(&normalized)
+    }
 }
 
 /// display an Amount in decimal string form (like "10.33")
@@ -197,11 +394,75 @@ impl fmt::Debug for Amount {
 impl FromStr for Amount {
     type Err = ModelsError;
 
+    /// Parse an amount from a decimal string.
+    /// Underscores and an `e`/`E` exponent made of an optional sign and digits are accepted.
+    /// Commas are accepted as thousands separators (see `Amount::from_human_str`),
+    /// but cannot be combined with an exponent.
+    ///
+    /// ## Example
+    /// ```
+    /// # use massa_models::amount::Amount;
+    /// # use std::str::FromStr;
+    /// let expected = Amount::from_str("1000000").unwrap();
+    /// assert_eq!(Amount::from_str("1e6").unwrap(), expected);
+    /// assert_eq!(Amount::from_str("1E6").unwrap(), expected);
+    /// assert_eq!(Amount::from_str("0.001e9").unwrap(), expected);
+    /// assert_eq!(Amount::from_str("1_000_000").unwrap(), expected);
+    /// assert_eq!(Amount::from_str("1,000,000").unwrap(), expected);
+    /// assert_eq!(Amount::from_str("1,000").unwrap(), Amount::from_str("1000").unwrap());
+    /// assert_eq!(Amount::from_str("1_000").unwrap(), Amount::from_str("1000").unwrap());
+    /// assert_eq!(Amount::from_str("15e-1").unwrap(), Amount::from_str("1.5").unwrap());
+    /// assert!(Amount::from_str("1e").is_err());
+    /// assert!(Amount::from_str("e6").is_err());
+    /// assert!(Amount::from_str("1,,0").is_err());
+    /// assert!(Amount::from_str("1,5").is_err());
+    /// assert!(Amount::from_str("1,000e3").is_err());
+    /// assert!(Amount::from_str("1e-10").is_err());
+    /// assert!(Amount::from_str("1,0e3").is_err());
+    /// assert!(Amount::from_str("1,e00").is_err());
+    /// assert!(Amount::from_str("1,000.5e3").is_err());
+    /// assert!(Amount::from_str("1e3e3").is_err());
+    /// assert!(Amount::from_str("1e3.5").is_err());
+    /// assert!(Amount::from_str("1e_3").is_err());
+    /// // the absolute value of the exponent is bounded
+    /// assert!(Amount::from_str("1e999999999").is_err());
+    /// assert!(Amount::from_str("1e99999999999999999999").is_err());
+    /// assert!(Amount::from_str("1e-40").is_err());
+    /// assert!(Amount::from_str("0e-40").is_err());
+    /// assert!(Amount::from_str("1e29").is_err());
+    /// assert_eq!(Amount::from_str("0.0000000000000000001e25").unwrap(), expected);
+    /// ```
     fn from_str(str_amount: &str) -> Result<Self, Self::Err> {
-        let res = Decimal::from_str(str_amount)
-            .map_err(|err| ModelsError::AmountParseError(err.to_string()))?
-            .checked_mul(AMOUNT_DECIMAL_FACTOR.into())
-            .ok_or_else(|| ModelsError::AmountParseError("amount is too large".to_string()))?;
+        if str_amount.contains(',') {
+            return Amount::from_human_str(str_amount);
+        }
+        if let Some((_, exponent)) = str_amount.split_once(['e', 'E']) {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err(ModelsError::AmountParseError(format!(
+                    "invalid exponent in amount {}",
+                    str_amount
+                )));
+            }
+            // bound the exponent explicitly to the decimal scale, without relying on the parser
+            let exponent_in_bounds = matches!(
+                digits.parse::<u32>(),
+                Ok(abs_exponent) if abs_exponent <= MAX_AMOUNT_EXPONENT
+            );
+            if !exponent_in_bounds {
+                return Err(ModelsError::AmountParseError(format!(
+                    "exponent of amount {} is out of bounds: its absolute value cannot exceed {}",
+                    str_amount, MAX_AMOUNT_EXPONENT
+                )));
+            }
+        }
+        let res = match str_amount.contains(['e', 'E']) {
+            true => Decimal::from_scientific(str_amount),
+            false => Decimal::from_str(str_amount),
+        }
+        .map_err(|err| ModelsError::AmountParseError(err.to_string()))?
+        .checked_mul(AMOUNT_DECIMAL_FACTOR.into())
+        .ok_or_else(|| ModelsError::AmountParseError("amount is too large".to_string()))?;
         if res.is_sign_negative() {
             return Err(ModelsError::AmountParseError(
                 "amounts cannot be strictly negative".to_string(),
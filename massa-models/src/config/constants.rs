@@ -133,8 +133,20 @@ pub const MAX_DATASTORE_KEY_LENGTH: u8 = 255;
 pub const MAX_OPERATION_DATASTORE_KEY_LENGTH: u8 = MAX_DATASTORE_KEY_LENGTH;
 /// Maximum length of a datastore value
 pub const MAX_DATASTORE_VALUE_LENGTH: u64 = 10_000_000;
+/// Maximum total size in bytes of the datastore keys and values of a single address
+pub const MAX_DATASTORE_SIZE_PER_ADDRESS: u64 = 100_000_000;
 /// Maximum length of a datastore value
 pub const MAX_BYTECODE_LENGTH: u64 = 10_000_000;
+/// Maximum gas that can be spent compiling a smart contract module, charged at one gas unit per bytecode byte
+pub const MAX_COMPILE_GAS: u64 = 10_000_000;
+/// Maximum depth of the call stack of a smart contract execution
+pub const MAX_CALL_DEPTH: u64 = 100;
+/// Maximum number of addresses owned by a single call stack frame
+pub const MAX_OWNED_ADDRESSES_PER_CALL: u64 = 1_000;
+/// Maximum number of events emitted by smart contracts during the execution of a slot
+pub const MAX_EVENTS_PER_SLOT: u64 = 10_000;
+/// Maximum total size of the data of the events emitted by smart contracts during the execution of a slot
+pub const MAX_EVENT_DATA_SIZE_PER_SLOT: u64 = 10_000_000;
 /// Maximum length of an operation datastore value
 pub const MAX_OPERATION_DATASTORE_VALUE_LENGTH: u64 = 500_000;
 /// Maximum ledger changes in a block
@@ -50,6 +50,8 @@ pub enum ModelsError {
     TimeOverflowError,
     /// Time error {0}
     TimeError(#[from] massa_time::TimeError),
+    /// invalid genesis timestamp: {0}
+    InvalidGenesisTimestamp(String),
     /// invalid roll update: {0}
     InvalidRollUpdate(String),
     /// Ledger changes, Amount overflow
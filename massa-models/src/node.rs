@@ -4,7 +4,7 @@ use crate::error::ModelsError;
 use massa_serialization::{
     DeserializeError, Deserializer, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
 };
-use massa_signature::PublicKey;
+use massa_signature::{PublicKey, HEX_PREFIX};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
 use std::ops::Bound::Included;
 
@@ -27,6 +27,11 @@ impl NodeId {
     pub fn get_public_key(&self) -> PublicKey {
         self.0
     }
+
+    /// Serializes the `NodeId` as the `0x` prefixed hexadecimal string of its public key bytes.
+    pub fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
 }
 
 impl std::fmt::Display for NodeId {
@@ -69,6 +74,11 @@ impl std::str::FromStr for NodeId {
     /// assert_eq!(node_id, res_node_id);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(HEX_PREFIX) {
+            return Ok(NodeId(
+                PublicKey::from_hex(s).map_err(|_| ModelsError::NodeIdParseError)?,
+            ));
+        }
         let mut chars = s.chars();
         match chars.next() {
             Some(prefix) if prefix == NODEID_PREFIX => {
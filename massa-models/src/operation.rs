@@ -186,6 +186,17 @@ impl OperationId {
     }
 }
 
+/// Computes the root hash of a list of operation ids, as stored in `BlockHeader::operation_merkle_root`.
+/// It is the hash of the concatenation of the ids in block order,
+/// and must be used by block producers and verifiers alike so that they always agree.
+pub fn compute_operations_merkle_root(operation_ids: &[OperationId]) -> Hash {
+    let mut data = Vec::with_capacity(operation_ids.len().saturating_mul(OPERATION_ID_SIZE_BYTES));
+    for operation_id in operation_ids {
+        data.extend(operation_id.to_bytes());
+    }
+    Hash::compute_from(&data)
+}
+
 /// Serializer for `OperationId`
 #[derive(Default, Clone)]
 pub struct OperationIdSerializer;
@@ -231,13 +242,20 @@ impl Deserializer<OperationId> for OperationIdDeserializer {
     }
 }
 
-#[derive(IntoPrimitive, Debug, Eq, PartialEq, TryFromPrimitive)]
+/// Kind of an operation, without its content (see `OperationType::kind`).
+/// The values are the ones used to identify the operation type in its binary serialization.
+#[derive(IntoPrimitive, Debug, Clone, Copy, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[repr(u32)]
-enum OperationTypeId {
+pub enum OperationTypeId {
+    /// see `OperationType::Transaction`
     Transaction = 0,
+    /// see `OperationType::RollBuy`
     RollBuy = 1,
+    /// see `OperationType::RollSell`
     RollSell = 2,
+    /// see `OperationType::ExecuteSC`
     ExecuteSC = 3,
+    /// see `OperationType::CallSC`
     CallSC = 4,
 }
 
@@ -263,6 +281,14 @@ impl std::fmt::Display for Operation {
     }
 }
 
+impl Operation {
+    /// Returns true if the operation can no longer be included in a block at `current_period`,
+    /// i.e. if `current_period` is strictly after `expire_period`.
+    pub fn is_expired(&self, current_period: u64) -> bool {
+        current_period > self.expire_period
+    }
+}
+
 /// signed operation
 pub type SecureShareOperation = SecureShare<Operation, OperationId>;
 
@@ -421,8 +447,14 @@ impl Deserializer<Operation> for OperationDeserializer {
 }
 
 /// Type specific operation content
+///
+/// The JSON representation is an object tagged by a `type` field holding the variant name,
+/// the other fields being the ones of the variant. For example:
+/// `{"type":"RollBuy","roll_count":1}`.
+/// The tag does not depend on the order of the variants: it is part of the public API and must stay stable.
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
 pub enum OperationType {
     /// transfer coins from sender to recipient
     Transaction {
@@ -512,6 +544,46 @@ impl std::fmt::Display for OperationType {
     }
 }
 
+impl OperationType {
+    /// Returns the kind of the operation
+    pub fn kind(&self) -> OperationTypeId {
+        match self {
+            OperationType::Transaction { .. } => OperationTypeId::Transaction,
+            OperationType::RollBuy { .. } => OperationTypeId::RollBuy,
+            OperationType::RollSell { .. } => OperationTypeId::RollSell,
+            OperationType::ExecuteSC { .. } => OperationTypeId::ExecuteSC,
+            OperationType::CallSC { .. } => OperationTypeId::CallSC,
+        }
+    }
+
+    /// Returns true if the operation is a coin transfer
+    pub fn is_transaction(&self) -> bool {
+        self.kind() == OperationTypeId::Transaction
+    }
+
+    /// Returns true if the operation buys or sells rolls
+    pub fn is_roll_operation(&self) -> bool {
+        matches!(
+            self.kind(),
+            OperationTypeId::RollBuy | OperationTypeId::RollSell
+        )
+    }
+
+    /// Returns true if the operation executes smart contract bytecode
+    pub fn is_smart_contract(&self) -> bool {
+        matches!(
+            self.kind(),
+            OperationTypeId::ExecuteSC | OperationTypeId::CallSC
+        )
+    }
+
+    /// Returns true if executing the operation may change roll counts:
+    /// roll operations do, and so may smart contracts through the roll ABIs
+    pub fn affects_rolls(&self) -> bool {
+        self.is_roll_operation() || self.is_smart_contract()
+    }
+}
+
 /// Serializer for `OperationType`
 pub struct OperationTypeSerializer {
     u32_serializer: U32VarIntSerializer,
@@ -804,6 +876,18 @@ impl SecureShareOperation {
         start..=self.content.expire_period
     }
 
+    /// Returns true if the operation can no longer be included in a block at `current_period`
+    pub fn is_expired(&self, current_period: u64) -> bool {
+        self.content.is_expired(current_period)
+    }
+
+    /// Returns true if `period` is within the validity range of the operation
+    /// (see `get_validity_range`)
+    pub fn is_valid_in_period(&self, period: u64, operation_validity_period: u64) -> bool {
+        self.get_validity_range(operation_validity_period)
+            .contains(&period)
+    }
+
     /// Get the max amount of gas used by the operation (`max_gas`)
     pub fn get_gas_usage(&self) -> u64 {
         match &self.content.op {
@@ -1283,6 +1367,22 @@ mod tests {
     use serial_test::serial;
     use std::collections::BTreeMap;
 
+    #[test]
+    fn test_compute_operations_merkle_root() {
+        assert_eq!(compute_operations_merkle_root(&[]), Hash::compute_from(&[]));
+        let operation_ids: Vec<OperationId> = ["op1", "op2", "op3"]
+            .iter()
+            .map(|name| OperationId::new(Hash::compute_from(name.as_bytes())))
+            .collect();
+        assert_eq!(
+            compute_operations_merkle_root(&operation_ids),
+            Hash::from_bytes(&[
+                192, 70, 160, 153, 197, 150, 44, 58, 102, 116, 252, 90, 182, 205, 8, 117, 147, 14,
+                99, 216, 244, 131, 194, 119, 35, 44, 220, 20, 242, 216, 99, 20
+            ])
+        );
+    }
+
     #[test]
     #[serial]
     fn test_transaction() {
@@ -1505,4 +1605,144 @@ mod tests {
 
         assert_eq!(op.get_validity_range(10), 40..=50);
     }
+
+    #[test]
+    #[serial]
+    fn test_operation_type_json_snapshot() {
+        let address =
+            Address::from_str("A12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+        let amount = Amount::from_mantissa_scale(3, 0);
+        let snapshots = vec![
+            (
+                OperationType::Transaction {
+                    recipient_address: address,
+                    amount,
+                },
+                r#"{"type":"Transaction","recipient_address":"A12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x","amount":"3"}"#,
+            ),
+            (
+                OperationType::RollBuy { roll_count: 2 },
+                r#"{"type":"RollBuy","roll_count":2}"#,
+            ),
+            (
+                OperationType::RollSell { roll_count: 1 },
+                r#"{"type":"RollSell","roll_count":1}"#,
+            ),
+            (
+                OperationType::ExecuteSC {
+                    data: vec![1, 2],
+                    max_gas: 100,
+                    datastore: BTreeMap::from([(vec![1], vec![2])]),
+                },
+                r#"{"type":"ExecuteSC","data":[1,2],"max_gas":100,"datastore":[[[1],[2]]]}"#,
+            ),
+            (
+                OperationType::CallSC {
+                    target_addr: address,
+                    target_func: "f".to_string(),
+                    param: vec![3],
+                    max_gas: 100,
+                    coins: amount,
+                },
+                r#"{"type":"CallSC","target_addr":"A12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x","target_func":"f","param":[3],"max_gas":100,"coins":"3"}"#,
+            ),
+        ];
+        for (op, snapshot) in snapshots {
+            assert_eq!(serde_json::to_string(&op).unwrap(), snapshot);
+            assert_eq!(serde_json::from_str::<OperationType>(snapshot).unwrap(), op);
+        }
+    }
+
+    #[test]
+    fn test_operation_type_classification() {
+        let address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let cases = [
+            (
+                OperationType::Transaction {
+                    recipient_address: address,
+                    amount: Amount::default(),
+                },
+                OperationTypeId::Transaction,
+                (true, false, false, false),
+            ),
+            (
+                OperationType::RollBuy { roll_count: 1 },
+                OperationTypeId::RollBuy,
+                (false, true, false, true),
+            ),
+            (
+                OperationType::RollSell { roll_count: 1 },
+                OperationTypeId::RollSell,
+                (false, true, false, true),
+            ),
+            (
+                OperationType::ExecuteSC {
+                    data: vec![],
+                    max_gas: 1,
+                    datastore: BTreeMap::new(),
+                },
+                OperationTypeId::ExecuteSC,
+                (false, false, true, true),
+            ),
+            (
+                OperationType::CallSC {
+                    target_addr: address,
+                    target_func: "f".to_string(),
+                    param: vec![],
+                    max_gas: 1,
+                    coins: Amount::default(),
+                },
+                OperationTypeId::CallSC,
+                (false, false, true, true),
+            ),
+        ];
+        for (op, kind, (transaction, roll, smart_contract, affects_rolls)) in cases {
+            assert_eq!(op.kind(), kind);
+            assert_eq!(op.is_transaction(), transaction, "{:?}", kind);
+            assert_eq!(op.is_roll_operation(), roll, "{:?}", kind);
+            assert_eq!(op.is_smart_contract(), smart_contract, "{:?}", kind);
+            assert_eq!(op.affects_rolls(), affects_rolls, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn test_operation_expiry() {
+        let keypair = KeyPair::generate();
+        let content = Operation {
+            fee: Amount::default(),
+            op: OperationType::RollBuy { roll_count: 1 },
+            expire_period: 50,
+        };
+        let op = Operation::new_verifiable(content, OperationSerializer::new(), &keypair).unwrap();
+
+        // exactly at expiry the operation is still valid
+        assert!(!op.is_expired(49));
+        assert!(!op.is_expired(50));
+        assert!(op.is_expired(51));
+        assert!(op.is_valid_in_period(50, 10));
+        assert!(op.is_valid_in_period(40, 10));
+        assert!(!op.is_valid_in_period(39, 10));
+        assert!(!op.is_valid_in_period(51, 10));
+
+        // the start of the validity range saturates at period 0
+        let content = Operation {
+            fee: Amount::default(),
+            op: OperationType::RollBuy { roll_count: 1 },
+            expire_period: 3,
+        };
+        let op = Operation::new_verifiable(content, OperationSerializer::new(), &keypair).unwrap();
+        assert!(op.is_valid_in_period(0, 10));
+        assert!(!op.is_valid_in_period(4, 10));
+
+        // an operation expiring at the last period never expires
+        let content = Operation {
+            fee: Amount::default(),
+            op: OperationType::RollBuy { roll_count: 1 },
+            expire_period: u64::MAX,
+        };
+        let op = Operation::new_verifiable(content, OperationSerializer::new(), &keypair).unwrap();
+        assert!(!op.is_expired(u64::MAX));
+        assert!(op.is_valid_in_period(u64::MAX, 10));
+        assert!(!op.is_valid_in_period(u64::MAX - 11, 10));
+    }
 }
@@ -763,4 +763,37 @@ mod tests {
         let res = array_from_slice(&zero.to_be_bytes()).unwrap();
         assert_eq!(zero, u64::from_be_bytes(res));
     }
+
+    /// Number of random values checked by `check_binary_serde_roundtrip`
+    const SERDE_ROUNDTRIP_SAMPLES: usize = 1_000;
+
+    /// Generates random values with `generate`, serializes each of them with a binary
+    /// (non-human-readable) serde format, deserializes it back and checks that the value is unchanged.
+    fn check_binary_serde_roundtrip<T, F>(mut generate: F)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+        F: FnMut(&mut rand::rngs::ThreadRng) -> T,
+    {
+        let mut rng = rand::thread_rng();
+        for _ in 0..SERDE_ROUNDTRIP_SAMPLES {
+            let value = generate(&mut rng);
+            let serialized = bincode::serialize(&value).unwrap();
+            let deserialized: T = bincode::deserialize(&serialized).unwrap();
+            assert_eq!(value, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_binary_serde_roundtrip() {
+        use crate::{address::Address, amount::Amount, operation::OperationId, slot::Slot};
+        use massa_hash::{Hash, HASH_SIZE_BYTES};
+        use rand::Rng;
+
+        check_binary_serde_roundtrip(|rng| {
+            Address(Hash::from_bytes(&rng.gen::<[u8; HASH_SIZE_BYTES]>()))
+        });
+        check_binary_serde_roundtrip(|rng| Slot::new(rng.gen(), rng.gen()));
+        check_binary_serde_roundtrip(|rng| Amount::from_raw(rng.gen()));
+        check_binary_serde_roundtrip(|rng| OperationId::from_bytes(&rng.gen()));
+    }
 }
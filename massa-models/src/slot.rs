@@ -211,6 +211,17 @@ impl Slot {
         Hash::compute_from(&self.to_bytes_key()).to_bytes()[0] >> 7 == 1
     }
 
+    /// number of slots in a cycle, saturating at `u64::MAX`
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use massa_models::slot::Slot;
+    /// assert_eq!(Slot::slots_per_cycle(128, 32), 4096);
+    /// ```
+    pub fn slots_per_cycle(periods_per_cycle: u64, thread_count: u8) -> u64 {
+        periods_per_cycle.saturating_mul(thread_count as u64)
+    }
+
     /// cycle associated to that slot
     pub fn get_cycle(&self, periods_per_cycle: u64) -> u64 {
         self.period / periods_per_cycle
@@ -328,6 +339,121 @@ impl Slot {
             .ok_or(ModelsError::PeriodOverflowError)?
             .saturating_sub(s.thread as u64))
     }
+
+    /// Returns the slot `n` slots after self, clamped at the last thread of period `u64::MAX`.
+    /// A `thread_count` of `0` is treated as `1`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use massa_models::slot::Slot;
+    /// assert_eq!(Slot::new(10, 3).saturating_add_slots(0, 5), Slot::new(10, 3));
+    /// assert_eq!(Slot::new(10, 3).saturating_add_slots(1, 5), Slot::new(10, 4));
+    /// assert_eq!(Slot::new(10, 3).saturating_add_slots(2, 5), Slot::new(11, 0));
+    /// assert_eq!(Slot::new(10, 3).saturating_add_slots(13, 5), Slot::new(13, 1));
+    /// // clamped at the last slot
+    /// let last = Slot::new(u64::MAX, 4);
+    /// assert_eq!(Slot::new(u64::MAX, 2).saturating_add_slots(1, 5), Slot::new(u64::MAX, 3));
+    /// assert_eq!(Slot::new(u64::MAX, 2).saturating_add_slots(3, 5), last);
+    /// assert_eq!(last.saturating_add_slots(u64::MAX, 5), last);
+    /// assert_eq!(
+    ///     Slot::new(0, 0).saturating_add_slots(u64::MAX, 32),
+    ///     Slot::new(u64::MAX / 32, 31)
+    /// );
+    /// ```
+    pub fn saturating_add_slots(&self, n: u64, thread_count: u8) -> Slot {
+        let thread_count = u128::from(thread_count.max(1));
+        let max_index = u128::from(u64::MAX) * thread_count + (thread_count - 1);
+        let index = (self.slot_index(thread_count) + u128::from(n)).min(max_index);
+        Slot::from_slot_index(index, thread_count)
+    }
+
+    /// Returns the slot `n` slots before self, clamped at the genesis slot `(0, 0)`.
+    /// A `thread_count` of `0` is treated as `1`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use massa_models::slot::Slot;
+    /// assert_eq!(Slot::new(10, 3).saturating_sub_slots(0, 5), Slot::new(10, 3));
+    /// assert_eq!(Slot::new(10, 3).saturating_sub_slots(3, 5), Slot::new(10, 0));
+    /// assert_eq!(Slot::new(10, 3).saturating_sub_slots(4, 5), Slot::new(9, 4));
+    /// assert_eq!(Slot::new(10, 3).saturating_sub_slots(13, 5), Slot::new(8, 0));
+    /// // clamped at genesis
+    /// assert_eq!(Slot::new(1, 1).saturating_sub_slots(6, 5), Slot::new(0, 0));
+    /// assert_eq!(Slot::new(1, 1).saturating_sub_slots(7, 5), Slot::new(0, 0));
+    /// assert_eq!(
+    ///     Slot::new(u64::MAX, 31).saturating_sub_slots(u64::MAX, 32),
+    ///     Slot::new(u64::MAX - u64::MAX / 32, 0)
+    /// );
+    /// ```
+    pub fn saturating_sub_slots(&self, n: u64, thread_count: u8) -> Slot {
+        let thread_count = u128::from(thread_count.max(1));
+        let index = self.slot_index(thread_count).saturating_sub(u128::from(n));
+        Slot::from_slot_index(index, thread_count)
+    }
+
+    /// Index of the slot counted from the genesis slot `(0, 0)`.
+    /// The thread is clamped to the last thread.
+    fn slot_index(&self, thread_count: u128) -> u128 {
+        u128::from(self.period) * thread_count + u128::from(self.thread).min(thread_count - 1)
+    }
+
+    /// Slot at a given index counted from the genesis slot `(0, 0)`.
+    /// The index must be lower than `(u64::MAX + 1) * thread_count`.
+    fn from_slot_index(index: u128, thread_count: u128) -> Slot {
+        Slot::new((index / thread_count) as u64, (index % thread_count) as u8)
+    }
+}
+
+/// Iterator over all the slots from `start` (included) to `end` (excluded), in chronological order
+///
+/// ## Example
+/// ```rust
+/// # use massa_models::slot::{Slot, SlotRange};
+/// let slots: Vec<Slot> = SlotRange::new(Slot::new(1, 1), Slot::new(2, 1), 2).collect();
+/// assert_eq!(slots, vec![Slot::new(1, 1), Slot::new(2, 0)]);
+/// assert_eq!(SlotRange::new(Slot::new(2, 1), Slot::new(2, 1), 2).count(), 0);
+/// assert_eq!(SlotRange::new(Slot::new(3, 0), Slot::new(2, 1), 2).count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlotRange {
+    /// next slot to yield, `None` if the iteration overflowed
+    next: Option<Slot>,
+    /// first slot that is not yielded
+    end: Slot,
+    /// number of threads
+    thread_count: u8,
+}
+
+impl SlotRange {
+    /// Creates an iterator over the slots from `start` (included) to `end` (excluded)
+    pub fn new(start: Slot, end: Slot, thread_count: u8) -> Self {
+        SlotRange {
+            next: Some(start),
+            end,
+            thread_count,
+        }
+    }
+}
+
+impl Iterator for SlotRange {
+    type Item = Slot;
+
+    fn next(&mut self) -> Option<Slot> {
+        let slot = self.next.filter(|slot| slot < &self.end)?;
+        self.next = slot.get_next_slot(self.thread_count).ok();
+        Some(slot)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self
+            .next
+            .and_then(|slot| self.end.slots_since(&slot, self.thread_count).ok())
+            .unwrap_or(0);
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
 }
 
 /// When an address is drawn to create an endorsement it is selected for a specific index
@@ -344,3 +470,56 @@ impl std::fmt::Display for IndexedSlot {
         writeln!(f, "Slot: {}, Index: {}", self.slot, self.index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIODS_PER_CYCLE: u64 = 4;
+    const THREAD_COUNT: u8 = 2;
+
+    #[test]
+    fn test_cycle_boundaries() {
+        for cycle in [0, 1, 7] {
+            let first = Slot::new_first_of_cycle(cycle, PERIODS_PER_CYCLE).unwrap();
+            let last = Slot::new_last_of_cycle(cycle, PERIODS_PER_CYCLE, THREAD_COUNT).unwrap();
+            assert_eq!(first, Slot::new(cycle * PERIODS_PER_CYCLE, 0));
+            assert_eq!(
+                last,
+                Slot::new((cycle + 1) * PERIODS_PER_CYCLE - 1, THREAD_COUNT - 1)
+            );
+            assert_eq!(first.get_cycle(PERIODS_PER_CYCLE), cycle);
+            assert_eq!(last.get_cycle(PERIODS_PER_CYCLE), cycle);
+            assert!(first.is_first_of_cycle(PERIODS_PER_CYCLE));
+            assert!(!first.is_last_of_cycle(PERIODS_PER_CYCLE, THREAD_COUNT));
+            assert!(last.is_last_of_cycle(PERIODS_PER_CYCLE, THREAD_COUNT));
+            assert!(!last.is_first_of_cycle(PERIODS_PER_CYCLE));
+            assert_eq!(
+                last.slots_since(&first, THREAD_COUNT).unwrap() + 1,
+                Slot::slots_per_cycle(PERIODS_PER_CYCLE, THREAD_COUNT)
+            );
+
+            // the slots around the boundaries belong to the neighbouring cycles
+            let next = last.get_next_slot(THREAD_COUNT).unwrap();
+            assert_eq!(next.get_cycle(PERIODS_PER_CYCLE), cycle + 1);
+            assert!(next.is_first_of_cycle(PERIODS_PER_CYCLE));
+            if cycle > 0 {
+                let prev = first.get_prev_slot(THREAD_COUNT).unwrap();
+                assert_eq!(prev.get_cycle(PERIODS_PER_CYCLE), cycle - 1);
+                assert!(prev.is_last_of_cycle(PERIODS_PER_CYCLE, THREAD_COUNT));
+            }
+        }
+    }
+
+    #[test]
+    fn test_genesis_cycle() {
+        assert_eq!(Slot::min().get_cycle(PERIODS_PER_CYCLE), 0);
+        assert_eq!(
+            Slot::new_first_of_cycle(0, PERIODS_PER_CYCLE).unwrap(),
+            Slot::min()
+        );
+        assert!(Slot::min().get_prev_slot(THREAD_COUNT).is_err());
+        assert!(Slot::new_first_of_cycle(u64::MAX, PERIODS_PER_CYCLE).is_err());
+        assert!(Slot::new_last_of_cycle(u64::MAX, PERIODS_PER_CYCLE, THREAD_COUNT).is_err());
+    }
+}
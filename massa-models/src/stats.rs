@@ -1,5 +1,6 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use crate::amount::Amount;
 use crate::slot::Slot;
 use massa_time::MassaTime;
 use serde::{Deserialize, Serialize};
@@ -18,6 +19,9 @@ pub struct ExecutionStats {
     pub final_executed_operations_count: usize,
     /// active execution cursor slot
     pub active_cursor: Slot,
+    /// time spent by the ABIs holding the execution context lock during the slots executed in the time window
+    /// (always zero unless the execution is built with the `context_lock_metrics` feature)
+    pub context_lock_hold_time: MassaTime,
 }
 
 impl std::fmt::Display for ExecutionStats {
@@ -44,6 +48,11 @@ impl std::fmt::Display for ExecutionStats {
             self.final_executed_operations_count
         )?;
         writeln!(f, "\tActive cursor: {}", self.active_cursor)?;
+        writeln!(
+            f,
+            "\tContext lock hold time: {}",
+            self.context_lock_hold_time
+        )?;
         Ok(())
     }
 }
@@ -111,12 +120,30 @@ impl std::fmt::Display for ConsensusStats {
 }
 
 /// stats produced by pool module
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct PoolStats {
     /// number of operations in the pool
     pub operation_count: u64,
     /// number of endorsement in the pool
     pub endorsement_count: u64,
+    /// total serialized size of the operations in the pool, in bytes
+    pub operation_bytes: u64,
+    /// number of transactions in the pool
+    pub transaction_count: u64,
+    /// number of roll buy operations in the pool
+    pub roll_buy_count: u64,
+    /// number of roll sell operations in the pool
+    pub roll_sell_count: u64,
+    /// number of `ExecuteSC` operations in the pool
+    pub execute_sc_count: u64,
+    /// number of `CallSC` operations in the pool
+    pub call_sc_count: u64,
+    /// lowest operation fee in the pool
+    pub min_fee: Option<Amount>,
+    /// highest operation fee in the pool
+    pub max_fee: Option<Amount>,
+    /// median operation fee in the pool (upper median if the operation count is even)
+    pub median_fee: Option<Amount>,
 }
 
 impl std::fmt::Display for PoolStats {
@@ -124,6 +151,21 @@ impl std::fmt::Display for PoolStats {
         writeln!(f, "Pool stats:")?;
         writeln!(f, "\tOperations: {}", self.operation_count)?;
         writeln!(f, "\tEndorsements: {}", self.endorsement_count)?;
+        writeln!(f, "\tOperation bytes: {}", self.operation_bytes)?;
+        writeln!(f, "\tTransactions: {}", self.transaction_count)?;
+        writeln!(f, "\tRoll buys: {}", self.roll_buy_count)?;
+        writeln!(f, "\tRoll sells: {}", self.roll_sell_count)?;
+        writeln!(f, "\tExecuteSC: {}", self.execute_sc_count)?;
+        writeln!(f, "\tCallSC: {}", self.call_sc_count)?;
+        if let (Some(min_fee), Some(max_fee), Some(median_fee)) =
+            (self.min_fee, self.max_fee, self.median_fee)
+        {
+            writeln!(
+                f,
+                "\tFees: min {}, max {}, median {}",
+                min_fee, max_fee, median_fee
+            )?;
+        }
         Ok(())
     }
 }
@@ -157,6 +157,44 @@ pub fn time_range_to_slot_range(
     Ok((start_slot, end_slot))
 }
 
+/// Checks that the genesis timestamp is within `tolerance` of the current time,
+/// to detect a genesis timestamp copied from another network or mistyped, which would give nonsensical slot timings.
+/// A zero `tolerance` disables the check.
+///
+/// # Arguments
+/// * `genesis_timestamp`: configured genesis timestamp
+/// * `now`: current time
+/// * `tolerance`: maximal distance between the genesis timestamp and the current time, in either direction
+/// * `allow_old_genesis`: accept a genesis timestamp further than `tolerance` in the past,
+///   for networks that have been running for longer than `tolerance`
+pub fn check_genesis_timestamp(
+    genesis_timestamp: MassaTime,
+    now: MassaTime,
+    tolerance: MassaTime,
+    allow_old_genesis: bool,
+) -> Result<(), ModelsError> {
+    if tolerance == MassaTime::from_millis(0) {
+        return Ok(());
+    }
+    if genesis_timestamp.saturating_sub(now) > tolerance {
+        return Err(ModelsError::InvalidGenesisTimestamp(format!(
+            "genesis timestamp {} is more than {} ms after the current time {}",
+            genesis_timestamp.to_utc_string(),
+            tolerance,
+            now.to_utc_string()
+        )));
+    }
+    if !allow_old_genesis && now.saturating_sub(genesis_timestamp) > tolerance {
+        return Err(ModelsError::InvalidGenesisTimestamp(format!(
+            "genesis timestamp {} is more than {} ms before the current time {}, old genesis timestamps are not allowed",
+            genesis_timestamp.to_utc_string(),
+            tolerance,
+            now.to_utc_string()
+        )));
+    }
+    Ok(())
+}
+
 /// TODO DOC
 pub fn get_closest_slot_to_timestamp(
     thread_count: u8,
@@ -226,6 +264,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_genesis_timestamp() {
+        let now = MassaTime::from_millis(1_700_000_000_000);
+        let day = MassaTime::from_millis(86_400_000);
+        let tolerance = day.checked_mul(30).unwrap();
+        assert!(check_genesis_timestamp(now, now, tolerance, false).is_ok());
+        assert!(check_genesis_timestamp(now.saturating_add(day), now, tolerance, false).is_ok());
+        assert!(check_genesis_timestamp(now.saturating_sub(day), now, tolerance, false).is_ok());
+
+        // a genesis copied from a network started years ago is refused,
+        // unless old genesis timestamps are allowed
+        let old_genesis = MassaTime::from_millis(1_600_000_000_000);
+        assert!(matches!(
+            check_genesis_timestamp(old_genesis, now, tolerance, false),
+            Err(ModelsError::InvalidGenesisTimestamp(_))
+        ));
+        assert!(check_genesis_timestamp(old_genesis, now, tolerance, true).is_ok());
+
+        // a genesis too far in the future is always refused
+        let future_genesis = now.saturating_add(tolerance).saturating_add(day);
+        for allow_old_genesis in [false, true] {
+            assert!(matches!(
+                check_genesis_timestamp(future_genesis, now, tolerance, allow_old_genesis),
+                Err(ModelsError::InvalidGenesisTimestamp(_))
+            ));
+        }
+
+        // a zero tolerance disables the check
+        let no_tolerance = MassaTime::from_millis(0);
+        assert!(check_genesis_timestamp(old_genesis, now, no_tolerance, false).is_ok());
+        assert!(check_genesis_timestamp(future_genesis, now, no_tolerance, false).is_ok());
+    }
+
     #[test]
     #[serial]
     fn test_time_range_to_slot_range() {
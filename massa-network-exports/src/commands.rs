@@ -79,6 +79,7 @@ use massa_models::{
     operation::{OperationId, OperationPrefixIds, SecureShareOperation},
     stats::NetworkStats,
 };
+use massa_time::MassaTime;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, net::IpAddr};
 use tokio::sync::oneshot;
@@ -103,6 +104,8 @@ pub enum NodeCommand {
     SendOperationAnnouncements(OperationPrefixIds),
     /// Ask for a set of operations
     AskForOperations(OperationPrefixIds),
+    /// Ask for a set of operations by their full ids
+    AskForOperationsById(Vec<OperationId>),
     /// Endorsements
     SendEndorsements(Vec<SecureShareEndorsement>),
     /// Ask peer list
@@ -131,6 +134,8 @@ pub enum NodeEventType {
     ReceivedOperationAnnouncements(OperationPrefixIds),
     /// Receive a list of wanted operations
     ReceivedAskForOperations(OperationPrefixIds),
+    /// Receive a list of wanted operations identified by their full ids
+    ReceivedAskForOperationsById(Vec<OperationId>),
     /// Receive a set of endorsement
     ReceivedEndorsements(Vec<SecureShareEndorsement>),
 }
@@ -183,6 +188,15 @@ pub enum NetworkCommand {
     NodeBanByIds(Vec<NodeId>),
     /// Ban a list of peer by their ip address
     NodeBanByIps(Vec<IpAddr>),
+    /// Disconnect a node and refuse its handshakes for a given duration
+    BanNode {
+        /// node id to ban
+        node_id: NodeId,
+        /// duration of the ban
+        duration: MassaTime,
+    },
+    /// Gracefully close the connection to a node, without banning it
+    CloseConnection(NodeId),
     /// Unban a list of peer by their node id
     NodeUnbanByIds(Vec<NodeId>),
     /// Unban a list of peer by their ip address
@@ -228,6 +242,13 @@ pub enum NetworkCommand {
         /// operation ids in the wish list
         wishlist: OperationPrefixIds,
     },
+    /// Ask for operations by their full ids
+    AskForOperationsById {
+        /// to node id
+        to_node: NodeId,
+        /// operation ids in the wish list
+        wishlist: Vec<OperationId>,
+    },
     /// Whitelist a list of `IpAddr`
     Whitelist(Vec<IpAddr>),
     /// Remove from whitelist a list of `IpAddr`
@@ -298,6 +319,13 @@ pub enum NetworkEvent {
         /// operation prefix ids
         operation_prefix_ids: OperationPrefixIds,
     },
+    /// Receive a list of asked operations identified by their full ids from `node`
+    ReceiveAskForOperationsById {
+        /// from node id
+        node: NodeId,
+        /// operation ids
+        operation_ids: Vec<OperationId>,
+    },
     /// received endorsements from node
     ReceivedEndorsements {
         /// node id
@@ -305,6 +333,13 @@ pub enum NetworkEvent {
         /// Endorsements
         endorsements: Vec<SecureShareEndorsement>,
     },
+    /// the number of advertisable peers known by the node dropped below the configured threshold
+    LowPeerCount {
+        /// number of advertisable peers currently known
+        current: usize,
+        /// configured threshold
+        target: usize,
+    },
 }
 
 /// Network management command
@@ -2,7 +2,7 @@
 
 use crate::{peers::PeerType, ConnectionId};
 use displaydoc::Display;
-use massa_models::error::ModelsError;
+use massa_models::{error::ModelsError, node::NodeId, version::Version};
 use massa_serialization::SerializeError;
 use std::net::IpAddr;
 use thiserror::Error;
@@ -55,6 +55,14 @@ pub enum NetworkError {
     SerializeError(#[from] SerializeError),
     /// container inconsistency error: {0}
     ContainerInconsistencyError(String),
+    /// node {0} sent a peer list of {1} ips, exceeding the limit of {2}
+    PeerListTooLong(NodeId, usize, u32),
+    /// node {0} sent a peer list too soon after the previous one
+    PeerListRateLimited(NodeId),
+    /// received a message of {0} bytes, exceeding the limit of {1} bytes
+    MessageTooLarge(u32, u32),
+    /// incompatible version: local version is {0}, remote version is {1}
+    IncompatibleVersion(Version, Version),
 }
 
 /// Handshake error type
@@ -72,8 +80,6 @@ pub enum HandshakeErrorType {
     HandshakeKey,
     /// Invalid signature
     HandshakeInvalidSignature,
-    /// Incompatible version
-    IncompatibleVersion,
     /// Outgoing connection returned a bootstrapable peer list: {0:?}
     PeerListReceived(Vec<IpAddr>),
 }
@@ -11,9 +11,10 @@ use massa_models::{
     composite::PubkeySig,
     endorsement::SecureShareEndorsement,
     node::NodeId,
-    operation::{OperationPrefixIds, SecureShareOperation},
+    operation::{OperationId, OperationPrefixIds, SecureShareOperation},
     stats::NetworkStats,
 };
+use massa_time::MassaTime;
 use std::{
     collections::{HashMap, VecDeque},
     net::IpAddr,
@@ -70,6 +71,30 @@ impl NetworkCommandSender {
         Ok(())
     }
 
+    /// disconnect a node and refuse its handshakes for `duration`
+    pub async fn node_ban_for(
+        &self,
+        node_id: NodeId,
+        duration: MassaTime,
+    ) -> Result<(), NetworkError> {
+        self.0
+            .send(NetworkCommand::BanNode { node_id, duration })
+            .await
+            .map_err(|_| NetworkError::ChannelError("could not send BanNode command".into()))?;
+        Ok(())
+    }
+
+    /// gracefully close the connection to a node, once the messages already queued for it are sent
+    pub async fn close_connection(&self, node_id: NodeId) -> Result<(), NetworkError> {
+        self.0
+            .send(NetworkCommand::CloseConnection(node_id))
+            .await
+            .map_err(|_| {
+                NetworkError::ChannelError("could not send CloseConnection command".into())
+            })?;
+        Ok(())
+    }
+
     /// remove from banned node(s) by id(s)
     pub async fn node_unban_by_ids(&self, ids: Vec<NodeId>) -> Result<(), NetworkError> {
         self.0
@@ -238,6 +263,22 @@ impl NetworkCommandSender {
         Ok(())
     }
 
+    /// Ask a target node (`to_node`) for operations identified by their full ids,
+    /// so that operations sharing a prefix are not confused.
+    pub async fn send_ask_for_operations_by_id(
+        &self,
+        to_node: NodeId,
+        wishlist: Vec<OperationId>,
+    ) -> Result<(), NetworkError> {
+        self.0
+            .send(NetworkCommand::AskForOperationsById { to_node, wishlist })
+            .await
+            .map_err(|_| {
+                NetworkError::ChannelError("could not send AskForOperationsById command".into())
+            })?;
+        Ok(())
+    }
+
     /// send endorsements to node id
     pub async fn send_endorsements(
         &self,
@@ -19,6 +19,9 @@ pub struct NetworkConfig {
     /// Time interval spent waiting for a response from a peer.
     /// In milliseconds
     pub connect_timeout: MassaTime,
+    /// Maximum duration of a whole handshake, whatever the time spent on each message.
+    /// In milliseconds
+    pub handshake_timeout: MassaTime,
     /// `Network_worker` will try to connect to available peers every `wakeup_interval`.
     /// In milliseconds
     pub wakeup_interval: MassaTime,
@@ -34,6 +37,9 @@ pub struct NetworkConfig {
     pub max_in_connections_per_ip: usize,
     /// Limit on the number of idle peers we remember.
     pub max_idle_peers: usize,
+    /// A `NetworkEvent::LowPeerCount` is emitted when the number of advertisable peers drops below this value.
+    /// Zero disables the notification.
+    pub low_peer_count_threshold: usize,
     /// Limit on the number of banned peers we remember.
     pub max_banned_peers: usize,
     /// Peer database is dumped every `peers_file_dump_interval` in milliseconds
@@ -66,12 +72,24 @@ pub struct NetworkConfig {
     pub thread_count: u8,
     /// Endorsement count
     pub endorsement_count: u32,
-    /// Max peer advertise length
+    /// Max peer advertise length, also the max number of ips accepted from a received peer list.
+    /// Longer received peer lists are truncated.
     pub max_peer_advertise_length: u32,
+    /// Minimum time between two accepted peer lists from the same node.
+    /// In milliseconds
+    pub min_peer_list_interval: MassaTime,
+    /// Number of rate limited or truncated peer lists after which a node is banned for `ban_timeout`
+    pub max_peer_list_violations: u32,
     /// Max endorsements per message
     pub max_endorsements_per_message: u32,
     /// Max message size
     pub max_message_size: u32,
+    /// Hard ceiling on the size of a single received message, in bytes.
+    /// Larger announced sizes are rejected before allocation and close the connection.
+    pub max_incoming_message_size: u32,
+    /// Messages of at least this size are compressed when the peer supports it.
+    /// None disables compression.
+    pub message_compression_threshold: Option<u32>,
     /// Maximum length of a datastore value
     pub max_datastore_value_length: u64,
     /// Maximum entry in an operation datastore
@@ -149,10 +167,12 @@ pub mod tests {
                 routable_ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
                 protocol_port: 0,
                 connect_timeout: MassaTime::from_millis(180_000),
+                handshake_timeout: MassaTime::from_millis(360_000),
                 wakeup_interval: MassaTime::from_millis(10_000),
                 peers_file: std::path::PathBuf::new(),
                 max_in_connections_per_ip: 2,
                 max_idle_peers: 3,
+                low_peer_count_threshold: 0,
                 max_banned_peers: 3,
                 peers_file_dump_interval: MassaTime::from_millis(10_000),
                 message_timeout: MassaTime::from_millis(5000u64),
@@ -173,8 +193,12 @@ pub mod tests {
                 max_endorsements_per_message: MAX_ENDORSEMENTS_PER_MESSAGE,
                 max_operations_per_block: MAX_OPERATIONS_PER_MESSAGE,
                 max_peer_advertise_length: MAX_ADVERTISE_LENGTH,
+                min_peer_list_interval: MassaTime::from_millis(1000),
+                max_peer_list_violations: 3,
                 thread_count: THREAD_COUNT,
                 max_message_size: MAX_MESSAGE_SIZE,
+                max_incoming_message_size: MAX_MESSAGE_SIZE,
+                message_compression_threshold: None,
                 max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
                 max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
                 max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -216,10 +240,12 @@ pub mod tests {
                 routable_ip,
                 protocol_port: port,
                 connect_timeout: MassaTime::from_millis(3000),
+                handshake_timeout: MassaTime::from_millis(6000),
                 peers_file: peers_file.to_path_buf(),
                 wakeup_interval: MassaTime::from_millis(3000),
                 max_in_connections_per_ip: 100,
                 max_idle_peers: 100,
+                low_peer_count_threshold: 0,
                 max_banned_peers: 100,
                 peers_file_dump_interval: MassaTime::from_millis(30000),
                 message_timeout: MassaTime::from_millis(5000u64),
@@ -240,8 +266,12 @@ pub mod tests {
                 max_endorsements_per_message: MAX_ENDORSEMENTS_PER_MESSAGE,
                 max_operations_per_block: MAX_OPERATIONS_PER_MESSAGE,
                 max_peer_advertise_length: 128,
+                min_peer_list_interval: MassaTime::from_millis(0),
+                max_peer_list_violations: 3,
                 thread_count: THREAD_COUNT,
                 max_message_size: MAX_MESSAGE_SIZE,
+                max_incoming_message_size: MAX_MESSAGE_SIZE,
+                message_compression_threshold: None,
                 max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
                 max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
                 max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -15,11 +15,17 @@ use std::convert::TryInto;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::warn;
 
+/// Once compression is negotiated, each message is preceded by one of these flags
+const UNCOMPRESSED_MESSAGE_FLAG: u8 = 0;
+const COMPRESSED_MESSAGE_FLAG: u8 = 1;
+
 /// Used to serialize and send data.
 pub struct WriteBinder {
     pub(crate) write_half: WriteHalf,
     message_index: u64,
     max_message_size: u32,
+    /// Messages at least this large are compressed, if compression was negotiated with the peer
+    compression_threshold: Option<u32>,
 }
 
 impl WriteBinder {
@@ -33,9 +39,16 @@ impl WriteBinder {
             write_half,
             message_index: 0,
             max_message_size,
+            compression_threshold: None,
         }
     }
 
+    /// Starts compressing the messages of at least `threshold` bytes.
+    /// Must only be called once the peer agreed to receive compressed messages.
+    pub fn enable_compression(&mut self, threshold: u32) {
+        self.compression_threshold = Some(threshold);
+    }
+
     /// Sends a serialized message.
     ///
     /// # Argument
@@ -43,14 +56,7 @@ impl WriteBinder {
     pub async fn send(&mut self, msg: &Message) -> Result<u64, NetworkError> {
         //        massa_trace!("binder.send", { "msg": msg });
         let mut buf = Vec::new();
-        MessageSerializer::new().serialize(msg, &mut buf)?;
-        let msg_size: u32 = buf
-            .len()
-            .try_into()
-            .map_err(|_| NetworkError::GeneralProtocolError("message too long".into()))?;
-        self.write_half
-            .write_all(&msg_size.to_be_bytes_min(self.max_message_size)?[..])
-            .await?;
+        self.serialize_framed(msg, &mut buf)?;
 
         // send message
         self.write_half.write_all(&buf).await?;
@@ -60,6 +66,32 @@ impl WriteBinder {
         //        massa_trace!("binder.send END", { "index": res_index });
         Ok(res_index)
     }
+
+    /// Appends a message and its size prefix to `buf`.
+    /// When compression is enabled, the message is preceded by a flag telling whether it is compressed.
+    fn serialize_framed(&self, msg: &Message, buf: &mut Vec<u8>) -> Result<(), NetworkError> {
+        let mut msg_buf = Vec::new();
+        MessageSerializer::new().serialize(msg, &mut msg_buf)?;
+        if let Some(threshold) = self.compression_threshold {
+            msg_buf = if msg_buf.len() >= threshold as usize {
+                let mut compressed = vec![COMPRESSED_MESSAGE_FLAG];
+                compressed.extend(lz4_flex::compress_prepend_size(&msg_buf));
+                compressed
+            } else {
+                let mut uncompressed = Vec::with_capacity(msg_buf.len() + 1);
+                uncompressed.push(UNCOMPRESSED_MESSAGE_FLAG);
+                uncompressed.extend(msg_buf);
+                uncompressed
+            };
+        }
+        let msg_size: u32 = msg_buf
+            .len()
+            .try_into()
+            .map_err(|_| NetworkError::GeneralProtocolError("message too long".into()))?;
+        buf.extend(msg_size.to_be_bytes_min(self.max_message_size)?);
+        buf.extend(msg_buf);
+        Ok(())
+    }
 }
 
 /// Used to receive and deserialize data.
@@ -70,7 +102,10 @@ pub struct ReadBinder {
     cursor: usize,
     msg_size: Option<u32>,
     max_message_size: u32,
+    max_incoming_message_size: u32,
     message_deserializer: MessageDeserializer,
+    /// Whether the peer was allowed to send compressed messages
+    compression: bool,
 }
 
 impl ReadBinder {
@@ -79,10 +114,13 @@ impl ReadBinder {
     /// # Argument
     /// * `read_half`: reader half.
     /// * `limit`: limit max bytes per second read.
+    /// * `max_message_size`: protocol-wide max message size, determines the size of the message length field.
+    /// * `max_incoming_message_size`: hard ceiling on the size of a single received message.
     pub fn new(
         read_half: ReadHalf,
         _limit: f64,
         max_message_size: u32,
+        max_incoming_message_size: u32,
         message_deserializer: MessageDeserializer,
     ) -> Self {
         ReadBinder {
@@ -92,7 +130,47 @@ impl ReadBinder {
             cursor: 0,
             msg_size: None,
             max_message_size,
+            max_incoming_message_size,
             message_deserializer,
+            compression: false,
+        }
+    }
+
+    /// Starts expecting a compression flag before each message.
+    /// Must only be called once the peer was told that it may send compressed messages.
+    pub fn enable_compression(&mut self) {
+        self.compression = true;
+    }
+
+    /// Decompresses a received message if needed, according to its compression flag.
+    /// The announced decompressed size is checked before anything is allocated for it.
+    fn decompress(&self, buf: &[u8]) -> Result<Vec<u8>, NetworkError> {
+        match buf.split_first() {
+            Some((&UNCOMPRESSED_MESSAGE_FLAG, msg)) => Ok(msg.to_vec()),
+            Some((&COMPRESSED_MESSAGE_FLAG, compressed)) => {
+                let size_bytes: [u8; 4] = compressed
+                    .get(..4)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .ok_or_else(|| {
+                        NetworkError::GeneralProtocolError("truncated compressed message".into())
+                    })?;
+                let size = u32::from_le_bytes(size_bytes);
+                if size > self.max_incoming_message_size {
+                    return Err(NetworkError::MessageTooLarge(
+                        size,
+                        self.max_incoming_message_size,
+                    ));
+                }
+                lz4_flex::decompress_size_prepended(compressed).map_err(|err| {
+                    NetworkError::GeneralProtocolError(format!(
+                        "could not decompress message: {}",
+                        err
+                    ))
+                })
+            }
+            _ => Err(NetworkError::GeneralProtocolError(
+                "invalid message compression flag".into(),
+            )),
         }
     }
 
@@ -140,6 +218,13 @@ impl ReadBinder {
 
             // once we have all the message size bytes, deserialize it
             let res_size = u32::from_be_bytes_min(&self.buf, self.max_message_size)?.0;
+            // reject oversized messages before allocating anything for them
+            if res_size > self.max_incoming_message_size {
+                return Err(NetworkError::MessageTooLarge(
+                    res_size,
+                    self.max_incoming_message_size,
+                ));
+            }
             // set self.msg_size to indicate that we are now in the process of reading the message contents (and not the size anymore).
             self.msg_size = Some(res_size);
             // allocate the buffer to match the message length
@@ -169,9 +254,16 @@ impl ReadBinder {
                 }
             }
         }
+        let decompressed;
+        let msg_buf = if self.compression {
+            decompressed = self.decompress(&self.buf)?;
+            &decompressed
+        } else {
+            &self.buf
+        };
         let (_, res_msg) = self
             .message_deserializer
-            .deserialize::<DeserializeError>(&self.buf)
+            .deserialize::<DeserializeError>(msg_buf)
             .map_err(|err| {
                 warn!("error deserializing message: {:?}", err);
                 NetworkError::ModelsError(ModelsError::DeserializeError(err.to_string()))
@@ -32,7 +32,7 @@ use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use tokio::{task::JoinHandle, time::timeout};
-use tracing::debug;
+use tracing::{debug, info};
 
 /// Type alias for more readability
 pub type HandshakeReturnType = Result<(NodeId, ReadBinder, WriteBinder), NetworkError>;
@@ -47,9 +47,13 @@ pub struct HandshakeWorker {
     self_node_id: NodeId,
     /// Our keypair.
     keypair: KeyPair,
-    /// After `timeout_duration` milliseconds, the handshake attempt is dropped.
+    /// After `timeout_duration` milliseconds without progress, the handshake attempt is dropped.
     timeout_duration: MassaTime,
+    /// After `handshake_timeout` milliseconds in total, the handshake attempt is dropped.
+    handshake_timeout: MassaTime,
     version: Version,
+    /// Messages at least this large are compressed if the peer supports it, None disables compression.
+    compression_threshold: Option<u32>,
 }
 
 impl HandshakeWorker {
@@ -66,9 +70,12 @@ impl HandshakeWorker {
     /// * `socket_writer`: sends data.
     /// * `self_node_id`: our node id.
     /// * `keypair`: our keypair.
-    /// * `timeout_duration`: after `timeout_duration` milliseconds, the handshake attempt is dropped.
+    /// * `timeout_duration`: after `timeout_duration` milliseconds waiting for a single message, the handshake attempt is dropped.
+    /// * `handshake_timeout`: after `handshake_timeout` milliseconds since its start, the handshake attempt is dropped.
     /// * `connection_id`: Node we are trying to connect for debugging
     /// * `version`: Node version used in handshake initialization (check peers compatibility)
+    /// * `max_incoming_message_size`: hard ceiling on the size of a single received message
+    /// * `compression_threshold`: minimal size of the compressed messages if both nodes support compression, None to disable it
     #[allow(clippy::too_many_arguments)]
     pub fn spawn(
         socket_reader: ReadHalf,
@@ -76,10 +83,13 @@ impl HandshakeWorker {
         self_node_id: NodeId,
         keypair: KeyPair,
         timeout_duration: MassaTime,
+        handshake_timeout: MassaTime,
         version: Version,
         connection_id: ConnectionId,
         max_bytes_read: f64,
         max_bytes_write: f64,
+        max_incoming_message_size: u32,
+        compression_threshold: Option<u32>,
     ) -> JoinHandle<(ConnectionId, HandshakeReturnType)> {
         debug!("starting handshake with connection_id={}", connection_id);
         massa_trace!("network_worker.new_connection", {
@@ -95,6 +105,7 @@ impl HandshakeWorker {
                         socket_reader,
                         max_bytes_read,
                         MAX_MESSAGE_SIZE,
+                        max_incoming_message_size,
                         MessageDeserializer::new(
                             THREAD_COUNT,
                             ENDORSEMENT_COUNT,
@@ -115,14 +126,26 @@ impl HandshakeWorker {
                     self_node_id,
                     keypair,
                     timeout_duration,
+                    handshake_timeout,
                     version,
+                    compression_threshold,
                 }
-                .run()
+                .run_with_deadline()
                 .await,
             )
         })
     }
 
+    /// Runs the handshake, aborting it with a `HandshakeTimeout` error
+    /// if it does not complete within `handshake_timeout`,
+    /// so that a peer answering each message just in time cannot hold the connection indefinitely.
+    async fn run_with_deadline(self) -> HandshakeReturnType {
+        match timeout(self.handshake_timeout.to_duration(), self.run()).await {
+            Ok(res) => res,
+            Err(_) => throw!(HandshakeTimeout),
+        }
+    }
+
     /// Manages one on going handshake.
     /// Consumes self.
     /// Returns a tuple `(ConnectionId, Result)`.
@@ -137,6 +160,7 @@ impl HandshakeWorker {
             public_key: self.self_node_id.get_public_key(),
             random_bytes: self_random_bytes,
             version: self.version,
+            compression: self.compression_threshold.is_some(),
         };
         let send_init_fut = self.writer.send(&msg);
 
@@ -144,7 +168,7 @@ impl HandshakeWorker {
         let recv_init_fut = self.reader.next();
 
         // join send_init_fut and recv_init_fut with a timeout, and match result
-        let (other_node_id, other_random_bytes, other_version) = match timeout(
+        let (other_node_id, other_random_bytes, other_version, other_compression) = match timeout(
             self.timeout_duration.to_duration(),
             try_join(send_init_fut, recv_init_fut),
         )
@@ -158,7 +182,8 @@ impl HandshakeWorker {
                     public_key: pk,
                     random_bytes: rb,
                     version,
-                } => (NodeId::new(pk), rb, version),
+                    compression,
+                } => (NodeId::new(pk), rb, version, compression),
                 Message::PeerList(list) => throw!(PeerListReceived, list),
                 _ => throw!(HandshakeWrongMessage),
             },
@@ -171,7 +196,14 @@ impl HandshakeWorker {
 
         // check if version is compatible with ours
         if !self.version.is_compatible(&other_version) {
-            throw!(IncompatibleVersion)
+            info!(
+                "aborting handshake with node {}: its version {} is incompatible with ours ({})",
+                other_node_id, other_version, self.version
+            );
+            return Err(NetworkError::IncompatibleVersion(
+                self.version,
+                other_version,
+            ));
         }
 
         // sign their random bytes
@@ -211,6 +243,12 @@ impl HandshakeWorker {
                 NetworkError::HandshakeError(HandshakeErrorType::HandshakeInvalidSignature)
             })?;
 
+        // both nodes announced compression support: the following messages may be compressed
+        if let (Some(threshold), true) = (self.compression_threshold, other_compression) {
+            self.writer.enable_compression(threshold);
+            self.reader.enable_compression();
+        }
+
         Ok((other_node_id, self.reader, self.writer))
     }
 }
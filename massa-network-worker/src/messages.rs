@@ -7,7 +7,7 @@ use massa_models::{
     config::HANDSHAKE_RANDOMNESS_SIZE_BYTES,
     endorsement::{Endorsement, EndorsementDeserializer, SecureShareEndorsement},
     operation::{
-        OperationIdsDeserializer, OperationIdsSerializer, OperationPrefixIds,
+        OperationId, OperationIdsDeserializer, OperationIdsSerializer, OperationPrefixIds,
         OperationPrefixIdsDeserializer, OperationPrefixIdsSerializer, OperationsDeserializer,
         OperationsSerializer, SecureShareOperation,
     },
@@ -23,8 +23,10 @@ use massa_serialization::{
 use massa_signature::{PublicKey, PublicKeyDeserializer, Signature, SignatureDeserializer};
 use nom::{
     bytes::complete::take,
+    combinator::opt,
     error::{context, ContextError, ParseError},
     multi::length_count,
+    number::complete::be_u8,
     sequence::tuple,
     IResult, Parser,
 };
@@ -46,6 +48,9 @@ pub enum Message {
         /// let us know their public key.
         random_bytes: [u8; HANDSHAKE_RANDOMNESS_SIZE_BYTES],
         version: Version,
+        /// Whether we accept compressed messages once the handshake is over.
+        /// Serialized as an optional trailing byte that legacy nodes ignore.
+        compression: bool,
     },
     /// Reply to a handshake initiation message.
     HandshakeReply {
@@ -73,6 +78,8 @@ pub enum Message {
     Operations(Vec<SecureShareOperation>),
     /// Endorsements
     Endorsements(Vec<SecureShareEndorsement>),
+    /// Someone ask for operations by their full ids.
+    AskForOperationsById(Vec<OperationId>),
 }
 
 #[derive(IntoPrimitive, Debug, Eq, PartialEq, TryFromPrimitive)]
@@ -89,6 +96,7 @@ pub(crate) enum MessageTypeId {
     AskForOperations,
     OperationsAnnouncement,
     ReplyForBlocks,
+    AskForOperationsById,
 }
 
 #[derive(IntoPrimitive, Debug, Eq, PartialEq, TryFromPrimitive)]
@@ -141,12 +149,16 @@ impl Serializer<Message> for MessageSerializer {
                 public_key,
                 random_bytes,
                 version,
+                compression,
             } => {
                 self.u32_serializer
                     .serialize(&(MessageTypeId::HandshakeInitiation as u32), buffer)?;
                 buffer.extend(public_key.to_bytes());
                 buffer.extend(random_bytes);
                 self.version_serializer.serialize(version, buffer)?;
+                if *compression {
+                    buffer.push(1);
+                }
             }
             Message::HandshakeReply { signature } => {
                 self.u32_serializer
@@ -242,6 +254,12 @@ impl Serializer<Message> for MessageSerializer {
                     self.secure_serializer.serialize(endorsement, buffer)?;
                 }
             }
+            Message::AskForOperationsById(operation_ids) => {
+                self.u32_serializer
+                    .serialize(&(MessageTypeId::AskForOperationsById as u32), buffer)?;
+                self.operations_ids_serializer
+                    .serialize(operation_ids, buffer)?;
+            }
         }
         Ok(())
     }
@@ -261,6 +279,7 @@ pub struct MessageDeserializer {
     endorsements_length_deserializer: U32VarIntDeserializer,
     endorsement_deserializer: SecureShareDeserializer<Endorsement, EndorsementDeserializer>,
     operation_prefix_ids_deserializer: OperationPrefixIdsDeserializer,
+    operation_ids_deserializer: OperationIdsDeserializer,
     infos_deserializer: OperationIdsDeserializer,
     ip_addr_deserializer: IpAddrDeserializer,
 }
@@ -321,6 +340,7 @@ impl MessageDeserializer {
             operation_prefix_ids_deserializer: OperationPrefixIdsDeserializer::new(
                 max_operations_per_message,
             ),
+            operation_ids_deserializer: OperationIdsDeserializer::new(max_operations_per_message),
             infos_deserializer: OperationIdsDeserializer::new(max_operations_per_block),
             ip_addr_deserializer: IpAddrDeserializer::new(),
         }
@@ -354,15 +374,19 @@ impl Deserializer<Message> for MessageDeserializer {
                         context("Failed version deserialization", |input| {
                             self.version_deserializer.deserialize(input)
                         }),
+                        context("Failed compression deserialization", opt(be_u8)),
                     ))
-                    .map(|(public_key, random_bytes, version)| {
-                        // Unwrap safety: we checked above that we took enough bytes
-                        Message::HandshakeInitiation {
-                            public_key,
-                            random_bytes: array_from_slice(random_bytes).unwrap(),
-                            version,
-                        }
-                    }),
+                    .map(
+                        |(public_key, random_bytes, version, compression)| {
+                            // Unwrap safety: we checked above that we took enough bytes
+                            Message::HandshakeInitiation {
+                                public_key,
+                                random_bytes: array_from_slice(random_bytes).unwrap(),
+                                version,
+                                compression: compression == Some(1),
+                            }
+                        },
+                    ),
                 )
                 .parse(input),
                 MessageTypeId::HandshakeReply => {
@@ -528,6 +552,13 @@ impl Deserializer<Message> for MessageDeserializer {
                 )
                 .map(Message::Endorsements)
                 .parse(input),
+                MessageTypeId::AskForOperationsById => {
+                    context("Failed AskForOperationsById deserialization", |input| {
+                        self.operation_ids_deserializer.deserialize(input)
+                    })
+                    .map(Message::AskForOperationsById)
+                    .parse(input)
+                }
             }
         })
         .parse(buffer)
@@ -576,6 +607,7 @@ mod tests {
             public_key: keypair.get_public_key(),
             random_bytes,
             version: Version::from_str("TEST.1.10").unwrap(),
+            compression: true,
         };
         let mut ser = Vec::new();
         message_serializer.serialize(&msg, &mut ser).unwrap();
@@ -588,16 +620,19 @@ mod tests {
                     public_key: pk1,
                     random_bytes: rb1,
                     version: v1,
+                    compression: c1,
                 },
                 Message::HandshakeInitiation {
                     public_key,
                     random_bytes,
                     version,
+                    compression,
                 },
             ) => {
                 assert_eq!(pk1, public_key);
                 assert_eq!(rb1, random_bytes);
                 assert_eq!(v1, version);
+                assert_eq!(c1, compression);
             }
             _ => panic!("unexpected message"),
         }
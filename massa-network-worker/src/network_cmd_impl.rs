@@ -29,13 +29,14 @@ use massa_models::{
     composite::PubkeySig,
     endorsement::SecureShareEndorsement,
     node::NodeId,
-    operation::{OperationPrefixIds, SecureShareOperation},
+    operation::{OperationId, OperationPrefixIds, SecureShareOperation},
     stats::NetworkStats,
 };
 use massa_network_exports::{
     AskForBlocksInfo, BlockInfoReply, BootstrapPeers, ConnectionClosureReason, ConnectionId,
     NetworkError, NodeCommand, Peer, Peers,
 };
+use massa_time::MassaTime;
 use std::{
     collections::{HashMap, HashSet},
     net::IpAddr,
@@ -173,6 +174,67 @@ pub async fn on_node_ban_by_ids_cmd(
     node_ban_by_ids(worker, ids).await
 }
 
+/// Disconnect the node `node_id` and refuse its handshakes until `duration` has elapsed
+pub async fn on_ban_node_cmd(
+    worker: &mut NetworkWorker,
+    node_id: NodeId,
+    duration: MassaTime,
+) -> Result<(), NetworkError> {
+    massa_trace!(
+        "network_worker.manage_network_command receive NetworkCommand::BanNode",
+        { "node_id": node_id, "duration": duration }
+    );
+    let now = MassaTime::now()?;
+    worker.banned_node_ids.retain(|_, ban_end| *ban_end > now);
+    worker
+        .banned_node_ids
+        .insert(node_id, now.saturating_add(duration));
+    if let Some((_, node_command_tx)) = worker.active_nodes.get(&node_id) {
+        if node_command_tx
+            .send(NodeCommand::Close(ConnectionClosureReason::Normal))
+            .await
+            .is_err()
+        {
+            massa_trace!(
+                "network.network_worker.manage_network_command", {"err": NetworkError::ChannelError(
+                    "close node command send failed".into(),
+                ).to_string()}
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Gracefully close the connection to the node `node_id`.
+///
+/// The node writer processes its commands in order, so the messages already queued for the node
+/// are flushed before it closes, each within the write timeout (`message_timeout`).
+/// The node is then removed from `active_nodes` and `NetworkEvent::ConnectionClosed` is emitted
+/// when its node worker ends, like for any other closure.
+pub async fn on_close_connection_cmd(
+    worker: &mut NetworkWorker,
+    node_id: NodeId,
+) -> Result<(), NetworkError> {
+    massa_trace!(
+        "network_worker.manage_network_command receive NetworkCommand::CloseConnection",
+        { "node_id": node_id }
+    );
+    if let Some((_, node_command_tx)) = worker.active_nodes.get(&node_id) {
+        if node_command_tx
+            .send(NodeCommand::Close(ConnectionClosureReason::Normal))
+            .await
+            .is_err()
+        {
+            massa_trace!(
+                "network.network_worker.manage_network_command", {"err": NetworkError::ChannelError(
+                    "close node command send failed".into(),
+                ).to_string()}
+            );
+        }
+    }
+    Ok(())
+}
+
 pub async fn on_send_block_header_cmd(
     worker: &mut NetworkWorker,
     node: NodeId,
@@ -424,6 +486,27 @@ pub async fn on_ask_for_operations_cmd(
         .await;
 }
 
+/// Network worker received the command `NetworkCommand::AskForOperationsById` from
+/// the controller: forward the wishlist of full operation ids to the `NodeWorker`.
+pub async fn on_ask_for_operations_by_id_cmd(
+    worker: &mut NetworkWorker,
+    to_node: NodeId,
+    wishlist: Vec<OperationId>,
+) {
+    massa_trace!(
+        "network_worker.manage_network_command receive NetworkCommand::AskForOperationsById",
+        { "wishlist": wishlist }
+    );
+    worker
+        .event
+        .forward(
+            to_node,
+            worker.active_nodes.get(&to_node),
+            NodeCommand::AskForOperationsById(wishlist),
+        )
+        .await;
+}
+
 fn get_connection_ids(
     worker: &mut NetworkWorker,
     node: &NodeId,
@@ -77,19 +77,19 @@ impl EventSender {
 }
 
 pub mod event_impl {
-    use crate::network_worker::NetworkWorker;
+    use crate::{network_cmd_impl::on_ban_node_cmd, network_worker::NetworkWorker};
     use massa_logging::massa_trace;
     use massa_models::{
         block_header::SecuredHeader,
         block_id::BlockId,
         endorsement::SecureShareEndorsement,
         node::NodeId,
-        operation::{OperationPrefixIds, SecureShareOperation},
+        operation::{OperationId, OperationPrefixIds, SecureShareOperation},
         secure_share::Id,
     };
     use massa_network_exports::{AskForBlocksInfo, BlockInfoReply, NodeCommand};
     use massa_network_exports::{NetworkError, NetworkEvent};
-    use std::net::IpAddr;
+    use std::{net::IpAddr, time::Instant};
     use tracing::{debug, info};
     macro_rules! evt_failed {
         ($err: ident) => {
@@ -108,10 +108,50 @@ pub mod event_impl {
             "node_id": from,
             "ips": list
         });
+        let now = Instant::now();
+        let min_interval = worker.cfg.min_peer_list_interval.to_duration();
+        if let Some(last) = worker.last_peer_list_received.get(&from) {
+            if now.saturating_duration_since(*last) < min_interval {
+                debug!("dropping peer list from node_id={}: rate limited", from);
+                return Err(NetworkError::PeerListRateLimited(from));
+            }
+        }
+        worker.last_peer_list_received.insert(from, now);
+        let max_length = worker.cfg.max_peer_advertise_length;
+        if list.len() > max_length as usize {
+            debug!(
+                "truncating peer list from node_id={} to {} ips",
+                from, max_length
+            );
+            worker
+                .peer_info_db
+                .merge_candidate_peers(&list[..max_length as usize])?;
+            return Err(NetworkError::PeerListTooLong(from, list.len(), max_length));
+        }
         worker.peer_info_db.merge_candidate_peers(list)?;
         Ok(())
     }
 
+    /// Counts a rate limited or truncated peer list of a node,
+    /// and bans the node for `ban_timeout` once it reaches `max_peer_list_violations`.
+    pub async fn on_peer_list_violation(
+        worker: &mut NetworkWorker,
+        from: NodeId,
+    ) -> Result<(), NetworkError> {
+        let violations = worker.peer_list_violations.entry(from).or_default();
+        *violations += 1;
+        if *violations < worker.cfg.max_peer_list_violations {
+            return Ok(());
+        }
+        info!(
+            "banning node_id={} after {} peer list violations",
+            from, violations
+        );
+        worker.peer_list_violations.remove(&from);
+        let ban_timeout = worker.cfg.ban_timeout;
+        on_ban_node_cmd(worker, from, ban_timeout).await
+    }
+
     pub async fn on_received_ask_for_blocks(
         worker: &mut NetworkWorker,
         from: NodeId,
@@ -260,6 +300,29 @@ pub mod event_impl {
         }
     }
 
+    /// The node worker signal that he received a list of operations required
+    /// by their full ids from another node.
+    pub async fn on_received_ask_for_operations_by_id(
+        worker: &mut NetworkWorker,
+        from: NodeId,
+        operation_ids: Vec<OperationId>,
+    ) {
+        massa_trace!(
+            "network_worker.on_node_event receive NetworkEvent::ReceiveAskForOperationsById",
+            { "operations": operation_ids }
+        );
+        if let Err(err) = worker
+            .event
+            .send(NetworkEvent::ReceiveAskForOperationsById {
+                node: from,
+                operation_ids,
+            })
+            .await
+        {
+            evt_failed!(err)
+        }
+    }
+
     pub async fn on_received_endorsements(
         worker: &mut NetworkWorker,
         from: NodeId,
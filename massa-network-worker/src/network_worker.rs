@@ -19,9 +19,11 @@ use massa_network_exports::{
     NetworkManagementCommand, NodeCommand, NodeEvent, NodeEventType, ReadHalf, WriteHalf,
 };
 use massa_signature::KeyPair;
+use massa_time::MassaTime;
 use std::{
     collections::{hash_map, HashMap, HashSet},
     net::{IpAddr, SocketAddr},
+    time::Instant,
 };
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
@@ -30,7 +32,7 @@ use tracing::{debug, trace, warn};
 /// Real job is done by network worker
 pub struct NetworkWorker {
     /// Network configuration.
-    cfg: NetworkConfig,
+    pub(crate) cfg: NetworkConfig,
     /// Our keypair.
     pub(crate) keypair: KeyPair,
     /// Our node id.
@@ -60,6 +62,14 @@ pub struct NetworkWorker {
         FuturesUnordered<JoinHandle<(NodeId, Result<ConnectionClosureReason, NetworkError>)>>,
     /// Map of connection to ip, `is_outgoing`.
     pub(crate) active_connections: HashMap<ConnectionId, (IpAddr, bool)>,
+    /// Instant of the last accepted peer list for each active node.
+    pub(crate) last_peer_list_received: HashMap<NodeId, Instant>,
+    /// Number of rate limited or truncated peer lists for each active node.
+    pub(crate) peer_list_violations: HashMap<NodeId, u32>,
+    /// Temporarily banned node ids, mapped to the end of their ban.
+    pub(crate) banned_node_ids: HashMap<NodeId, MassaTime>,
+    /// Whether a `NetworkEvent::LowPeerCount` was emitted since the peer count last went above the threshold.
+    low_peer_count_notified: bool,
     /// Node version
     version: Version,
     /// Event sender
@@ -119,10 +129,38 @@ impl NetworkWorker {
             active_nodes: HashMap::new(),
             node_worker_handles: FuturesUnordered::new(),
             active_connections: HashMap::new(),
+            last_peer_list_received: HashMap::new(),
+            peer_list_violations: HashMap::new(),
+            banned_node_ids: HashMap::new(),
+            low_peer_count_notified: false,
             version,
         }
     }
 
+    /// Emits a `NetworkEvent::LowPeerCount` when the number of advertisable peers
+    /// drops below `low_peer_count_threshold`.
+    /// The event is emitted only once until the peer count goes back above the threshold.
+    async fn check_peer_count(&mut self) {
+        let target = self.cfg.low_peer_count_threshold;
+        let current = self.peer_info_db.get_advertisable_peer_count();
+        if current >= target {
+            self.low_peer_count_notified = false;
+            return;
+        }
+        if self.low_peer_count_notified {
+            return;
+        }
+        warn!(
+            "only {} advertisable peers known, below the threshold of {}",
+            current, target
+        );
+        self.low_peer_count_notified = true;
+        let _ = self
+            .event
+            .send(NetworkEvent::LowPeerCount { current, target })
+            .await;
+    }
+
     /// Runs the main loop of the network worker
     /// There is a `tokio::select!` inside the loop
     pub async fn run_loop(mut self) -> Result<(), NetworkError> {
@@ -192,6 +230,7 @@ impl NetworkWorker {
                 // wake up interval
                 _ = wakeup_interval.tick() => {
                     self.peer_info_db.update()?; // notify tick to peer db
+                    self.check_peer_count().await;
 
                     need_connect_retry = true; // retry out connections
                 }
@@ -232,6 +271,8 @@ impl NetworkWorker {
                     let _ = self
                         .event.send(NetworkEvent::ConnectionClosed(node_id))
                         .await;
+                    self.last_peer_list_received.remove(&node_id);
+                    self.peer_list_violations.remove(&node_id);
                     if let Some((connection_id, _)) = self
                         .active_nodes
                         .remove(&node_id) {
@@ -311,6 +352,18 @@ impl NetworkWorker {
         Ok(())
     }
 
+    /// Checks whether a node id is temporarily banned, forgetting its ban if it expired.
+    fn is_node_banned(&mut self, node_id: &NodeId) -> Result<bool, NetworkError> {
+        match self.banned_node_ids.get(node_id) {
+            Some(ban_end) if *ban_end > MassaTime::now()? => Ok(true),
+            Some(_) => {
+                self.banned_node_ids.remove(node_id);
+                Ok(false)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Manages finished handshakes.
     /// Only used by the worker.
     ///
@@ -352,6 +405,21 @@ impl NetworkWorker {
                     return Ok(());
                 }
 
+                // node id is temporarily banned
+                if self.is_node_banned(&new_node_id)? {
+                    debug!(
+                        "connection_id={}, node_id={} node is banned",
+                        new_connection_id, new_node_id
+                    );
+                    massa_trace!("handshake_node_banned", {
+                        "connection_id": new_connection_id,
+                        "node_id": new_node_id
+                    });
+                    self.connection_closed(new_connection_id, ConnectionClosureReason::Normal)
+                        .await?;
+                    return Ok(());
+                }
+
                 match self.active_nodes.entry(new_node_id) {
                     // we already have this node ID
                     hash_map::Entry::Occupied(_) => {
@@ -513,6 +581,12 @@ impl NetworkWorker {
         match cmd {
             NetworkCommand::NodeBanByIps(ips) => on_node_ban_by_ips_cmd(self, ips).await?,
             NetworkCommand::NodeBanByIds(ids) => on_node_ban_by_ids_cmd(self, ids).await?,
+            NetworkCommand::BanNode { node_id, duration } => {
+                on_ban_node_cmd(self, node_id, duration).await?
+            }
+            NetworkCommand::CloseConnection(node_id) => {
+                on_close_connection_cmd(self, node_id).await?
+            }
             NetworkCommand::SendBlockHeader { node, header } => {
                 on_send_block_header_cmd(self, node, header).await?
             }
@@ -533,6 +607,9 @@ impl NetworkWorker {
             NetworkCommand::AskForOperations { to_node, wishlist } => {
                 on_ask_for_operations_cmd(self, to_node, wishlist).await
             }
+            NetworkCommand::AskForOperationsById { to_node, wishlist } => {
+                on_ask_for_operations_by_id_cmd(self, to_node, wishlist).await
+            }
             NetworkCommand::SendEndorsements { node, endorsements } => {
                 on_send_endorsements_cmd(self, node, endorsements).await
             }
@@ -705,6 +782,7 @@ impl NetworkWorker {
             let max_endorsements_per_message = self.cfg.max_endorsements_per_message;
             let max_operations_per_message = self.cfg.max_operations_per_message;
             let max_message_size = self.cfg.max_message_size;
+            let max_incoming_message_size = self.cfg.max_incoming_message_size;
             let max_datastore_value_length = self.cfg.max_datastore_value_length;
             let max_function_name_length = self.cfg.max_function_name_length;
             let max_parameters_size = self.cfg.max_parameters_size;
@@ -718,6 +796,7 @@ impl NetworkWorker {
                         reader,
                         max_bytes_write,
                         max_message_size,
+                        max_incoming_message_size,
                         MessageDeserializer::new(
                             thread_count,
                             endorsement_count,
@@ -775,10 +854,13 @@ impl NetworkWorker {
             self.self_node_id,
             self.keypair.clone(),
             self.cfg.connect_timeout,
+            self.cfg.handshake_timeout,
             self.version,
             connection_id,
             self.cfg.max_bytes_read,
             self.cfg.max_bytes_write,
+            self.cfg.max_incoming_message_size,
+            self.cfg.message_compression_threshold,
         ));
         Ok(())
     }
@@ -793,7 +875,16 @@ impl NetworkWorker {
         match evt {
             // received a list of peers
             NodeEvent(from_node_id, NodeEventType::ReceivedPeerList(lst)) => {
-                event_impl::on_received_peer_list(self, from_node_id, &lst)?
+                match event_impl::on_received_peer_list(self, from_node_id, &lst) {
+                    Err(
+                        err @ (NetworkError::PeerListTooLong(..)
+                        | NetworkError::PeerListRateLimited(_)),
+                    ) => {
+                        debug!("{}", err);
+                        event_impl::on_peer_list_violation(self, from_node_id).await?
+                    }
+                    res => res?,
+                }
             }
             NodeEvent(from_node_id, NodeEventType::ReceivedAskForBlocks(list)) => {
                 event_impl::on_received_ask_for_blocks(self, from_node_id, list).await
@@ -819,6 +910,9 @@ impl NetworkWorker {
             NodeEvent(node, NodeEventType::ReceivedAskForOperations(operation_ids)) => {
                 event_impl::on_received_ask_for_operations(self, node, operation_ids).await
             }
+            NodeEvent(node, NodeEventType::ReceivedAskForOperationsById(operation_ids)) => {
+                event_impl::on_received_ask_for_operations_by_id(self, node, operation_ids).await
+            }
         }
         Ok(())
     }
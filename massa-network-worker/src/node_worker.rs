@@ -261,6 +261,17 @@ async fn node_writer_handle(
                     .collect();
                 Some(messages)
             }
+            Some(NodeCommand::AskForOperationsById(operation_ids)) => {
+                massa_trace!(
+                    "node_worker.run_loop. send Message::AskForOperationsById",
+                    {"node": node_id, "operation_ids": operation_ids}
+                );
+                let messages = operation_ids
+                    .chunks(max_operations_per_message as usize)
+                    .map(|chunk| Message::AskForOperationsById(chunk.to_vec()))
+                    .collect();
+                Some(messages)
+            }
             Some(NodeCommand::SendEndorsements(endorsements)) => {
                 massa_trace!("node_worker.run_loop. send Message::SendEndorsements", {"node": node_id, "endorsements": endorsements});
                 // cut endorsement list if it exceed max_endorsements_per_message
@@ -381,6 +392,17 @@ async fn node_reader_handle(
                         );
                         send_node_event(node_event_tx, event, max_send_wait).await
                     }
+                    Message::AskForOperationsById(operation_ids) => {
+                        massa_trace!(
+                            "node_worker.run_loop. receive Message::AskForOperationsById: ",
+                            {"node": node_id, "operation_ids": operation_ids}
+                        );
+                        let event = NodeEvent(
+                            node_id,
+                            NodeEventType::ReceivedAskForOperationsById(operation_ids),
+                        );
+                        send_node_event(node_event_tx, event, max_send_wait).await
+                    }
                     Message::OperationsAnnouncement(operation_prefix_ids) => {
                         massa_trace!("node_worker.run_loop. receive Message::OperationsBatch", {"node": node_id, "operation_prefix_ids": operation_prefix_ids});
                         let event = NodeEvent(
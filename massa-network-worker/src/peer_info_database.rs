@@ -789,7 +789,17 @@ impl PeerInfoDatabase {
         &self.peers
     }
 
-    /// Returns a vector of advertisable `IpAddr` sorted by `( last_failure, rev(last_success) )`
+    /// Returns the number of known peers that are advertised and not banned
+    pub fn get_advertisable_peer_count(&self) -> usize {
+        self.peers
+            .values()
+            .filter(|p| p.advertised && !p.banned)
+            .count()
+    }
+
+    /// Returns a vector of at most `max_peer_advertise_length` advertisable `IpAddr`
+    /// sorted by `( rev(last_success), last_failure, ip )`.
+    /// The IP is used as a final tie-break so that the result does not depend on the iteration order of the peer map.
     pub fn get_advertisable_peer_ips(&self) -> Vec<IpAddr> {
         let mut sorted_peers: Vec<PeerInfo> = self
             .peers
@@ -797,7 +807,8 @@ impl PeerInfoDatabase {
             .filter(|&p| (p.advertised && !p.banned))
             .copied()
             .collect();
-        sorted_peers.sort_unstable_by_key(|&p| (std::cmp::Reverse(p.last_alive), p.last_failure));
+        sorted_peers
+            .sort_unstable_by_key(|&p| (std::cmp::Reverse(p.last_alive), p.last_failure, p.ip));
         let mut sorted_ips: Vec<IpAddr> = sorted_peers
             .into_iter()
             .take(self.network_settings.max_peer_advertise_length as usize)
@@ -2,7 +2,8 @@
 
 // To start alone RUST_BACKTRACE=1 cargo test -- --nocapture --test-threads=1
 use super::tools;
-use crate::messages::{Message, MessageDeserializer};
+use crate::handshake_worker::HandshakeWorker;
+use crate::messages::{Message, MessageDeserializer, MessageSerializer};
 use crate::node_worker::NodeWorker;
 use crate::tests::tools::{get_dummy_block_id, get_transaction};
 use crate::NetworkError;
@@ -26,21 +27,26 @@ use massa_models::{
     endorsement::{Endorsement, EndorsementSerializer},
     node::NodeId,
     secure_share::SecureShareContent,
+    serialization::SerializeMinBEInt,
     slot::Slot,
+    version::Version,
 };
 use massa_network_exports::{settings::PeerTypeConnectionConfig, NodeCommand, NodeEvent};
 use massa_network_exports::{
     AskForBlocksInfo, BlockInfoReply, ConnectionClosureReason, ConnectionId, HandshakeErrorType,
     PeerInfo, PeerType,
 };
+use massa_serialization::Serializer;
 use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use serial_test::serial;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     time::{Duration, Instant},
 };
+use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
 use tokio::time::sleep;
 use tracing::trace;
@@ -79,6 +85,7 @@ async fn test_node_worker_shutdown() {
         duplex_mock_read,
         f64::INFINITY,
         MAX_MESSAGE_SIZE,
+        MAX_MESSAGE_SIZE,
         MessageDeserializer::new(
             THREAD_COUNT,
             ENDORSEMENT_COUNT,
@@ -153,6 +160,7 @@ async fn test_node_worker_operations_message() {
         duplex_mock_read,
         f64::INFINITY,
         MAX_MESSAGE_SIZE,
+        MAX_MESSAGE_SIZE,
         MessageDeserializer::new(
             THREAD_COUNT,
             ENDORSEMENT_COUNT,
@@ -1023,6 +1031,212 @@ async fn test_retry_connection_closed() {
     .await;
 }
 
+/// Test that closing the connection to one of two nodes only disconnects that node.
+#[tokio::test]
+#[serial]
+async fn test_close_connection() {
+    // test config
+    let bind_port: u16 = 50_000;
+    let temp_peers_file = super::tools::generate_peers_file(&[]);
+    let network_conf = NetworkConfig {
+        peer_types_config: default_testing_peer_type_enum_map(),
+        ..NetworkConfig::scenarios_default(bind_port, temp_peers_file.path())
+    };
+
+    let mock1_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11)), bind_port);
+    let mock2_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(169, 202, 0, 12)), bind_port);
+
+    tools::network_test(
+        network_conf.clone(),
+        temp_peers_file,
+        async move |network_command_sender,
+                    mut network_event_receiver,
+                    network_manager,
+                    mut mock_interface| {
+            let (node1_id, conn1_r, _conn1_w) = tools::full_connection_to_controller(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock1_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(0),
+            )
+            .await;
+            let conn1_drain = tools::incoming_message_drain_start(conn1_r).await;
+            let (node2_id, conn2_r, _conn2_w) = tools::full_connection_to_controller(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock2_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(1),
+            )
+            .await;
+            let conn2_drain = tools::incoming_message_drain_start(conn2_r).await;
+
+            network_command_sender
+                .close_connection(node1_id)
+                .await
+                .expect("error during send close connection command.");
+
+            // Only the closed node must be reported as disconnected.
+            match tools::wait_network_event(&mut network_event_receiver, 1000.into(), |msg| {
+                match msg {
+                    NetworkEvent::ConnectionClosed(node) => Some(node),
+                    _ => None,
+                }
+            })
+            .await
+            {
+                Some(node) => assert_eq!(node, node1_id),
+                None => panic!("Timeout while waiting for connection closed event"),
+            }
+            assert!(
+                tools::wait_network_event(&mut network_event_receiver, 500.into(), |msg| {
+                    match msg {
+                        NetworkEvent::ConnectionClosed(node) if node == node2_id => Some(()),
+                        _ => None,
+                    }
+                })
+                .await
+                .is_none(),
+                "the other node was disconnected"
+            );
+            (
+                network_event_receiver,
+                network_manager,
+                mock_interface,
+                vec![conn1_drain, conn2_drain],
+            )
+        },
+    )
+    .await;
+}
+
+/// Test that a node banned for a duration is disconnected and that its handshakes are refused until the ban expires.
+#[tokio::test]
+#[serial]
+async fn test_ban_node_for_duration() {
+    // test config
+    let bind_port: u16 = 50_000;
+
+    let mock_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11)), bind_port);
+    let temp_peers_file = super::tools::generate_peers_file(&[PeerInfo {
+        ip: mock_addr.ip(),
+        peer_type: PeerType::Bootstrap,
+        last_alive: None,
+        last_failure: None,
+        advertised: true,
+        active_out_connection_attempts: 0,
+        active_out_connections: 0,
+        active_in_connections: 0,
+        banned: false,
+    }]);
+    let network_conf = NetworkConfig {
+        peer_types_config: default_testing_peer_type_enum_map(),
+        ..NetworkConfig::scenarios_default(bind_port, temp_peers_file.path())
+    };
+
+    tools::network_test(
+        network_conf.clone(),
+        temp_peers_file,
+        async move |network_command_sender,
+                    mut network_event_receiver,
+                    network_manager,
+                    mut mock_interface| {
+            let keypair = KeyPair::generate();
+            let (node_id, _read, _write) = tools::full_connection_to_controller_with_keypair(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(0),
+                keypair.clone(),
+            )
+            .await;
+
+            // Ban the node.
+            let ban_duration = MassaTime::from_millis(1_000);
+            network_command_sender
+                .node_ban_for(node_id, ban_duration)
+                .await
+                .expect("error during send ban command.");
+
+            // Make sure network sends a dis-connect event.
+            if let Some(node) =
+                tools::wait_network_event(&mut network_event_receiver, 1000.into(), |msg| match msg
+                {
+                    NetworkEvent::ConnectionClosed(node) => Some(node),
+                    _ => None,
+                })
+                .await
+            {
+                assert_eq!(node, node_id);
+            } else {
+                panic!("Timeout while waiting for connection closed event");
+            }
+
+            // A new handshake of the banned node must not lead to a connection.
+            let (mock_read_half, mock_write_half) = mock_interface
+                .connect_to_controller(&mock_addr)
+                .await
+                .expect("connection towards controller failed");
+            let _ = HandshakeWorker::spawn(
+                mock_read_half,
+                mock_write_half,
+                node_id,
+                keypair.clone(),
+                1_000u64.into(),
+                1_000u64.into(),
+                Version::from_str("TEST.1.10").unwrap(),
+                ConnectionId(1),
+                f64::INFINITY,
+                f64::INFINITY,
+                MAX_MESSAGE_SIZE,
+                None,
+            )
+            .await
+            .expect("handshake creation failed");
+            assert!(
+                tools::wait_network_event(&mut network_event_receiver, 200.into(), |msg| {
+                    match msg {
+                        NetworkEvent::NewConnection(node) if node == node_id => Some(()),
+                        _ => None,
+                    }
+                })
+                .await
+                .is_none(),
+                "banned node was accepted"
+            );
+
+            // The node is accepted again once the ban has expired.
+            sleep(ban_duration.to_duration()).await;
+            let (_node_id, _read, _write) = tools::full_connection_to_controller_with_keypair(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(2),
+                keypair,
+            )
+            .await;
+            (
+                network_event_receiver,
+                network_manager,
+                mock_interface,
+                vec![],
+            )
+        },
+    )
+    .await;
+}
+
 #[tokio::test]
 #[serial]
 async fn test_operation_messages() {
@@ -1273,3 +1487,524 @@ async fn test_endorsements_messages() {
     )
     .await;
 }
+
+/// Test that a read binder rejects a message whose announced size exceeds the incoming message size ceiling,
+/// without waiting for (or allocating) the message contents.
+#[tokio::test]
+#[serial]
+async fn test_read_binder_rejects_oversized_message() {
+    let max_incoming_message_size: u32 = 1024;
+    let (duplex_controller, mut duplex_mock) = tokio::io::duplex(64);
+    let (duplex_read, _duplex_write) = tokio::io::split(duplex_controller);
+    let mut reader = ReadBinder::new(
+        duplex_read,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        max_incoming_message_size,
+        MessageDeserializer::new(
+            THREAD_COUNT,
+            ENDORSEMENT_COUNT,
+            MAX_ADVERTISE_LENGTH,
+            MAX_ASK_BLOCKS_PER_MESSAGE,
+            MAX_OPERATIONS_PER_BLOCK,
+            MAX_OPERATIONS_PER_MESSAGE,
+            MAX_ENDORSEMENTS_PER_MESSAGE,
+            MAX_DATASTORE_VALUE_LENGTH,
+            MAX_FUNCTION_NAME_LENGTH,
+            MAX_PARAMETERS_SIZE,
+            MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+            MAX_OPERATION_DATASTORE_KEY_LENGTH,
+            MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        ),
+    );
+
+    // announce a huge message but only send its length prefix
+    let announced_size: u32 = MAX_MESSAGE_SIZE;
+    duplex_mock
+        .write_all(&announced_size.to_be_bytes_min(MAX_MESSAGE_SIZE).unwrap())
+        .await
+        .unwrap();
+
+    match tokio::time::timeout(Duration::from_millis(1000), reader.next()).await {
+        Ok(Err(NetworkError::MessageTooLarge(size, max))) => {
+            assert_eq!(size, announced_size);
+            assert_eq!(max, max_incoming_message_size);
+        }
+        Ok(other) => panic!("unexpected read binder result: {:?}", other),
+        Err(_) => panic!("read binder waited for the oversized message contents"),
+    }
+}
+
+/// Test that a handshake between nodes of different networks is aborted with an incompatible version error.
+#[tokio::test]
+#[serial]
+async fn test_handshake_incompatible_version() {
+    let (duplex_a, duplex_b) = tokio::io::duplex(65536);
+    let (read_a, write_a) = tokio::io::split(duplex_a);
+    let (read_b, write_b) = tokio::io::split(duplex_b);
+    let keypair_a = KeyPair::generate();
+    let keypair_b = KeyPair::generate();
+    let version_a = Version::from_str("TEST.1.10").unwrap();
+    let version_b = Version::from_str("MAIN.1.0").unwrap();
+
+    let handshake_a = HandshakeWorker::spawn(
+        read_a,
+        write_a,
+        NodeId::new(keypair_a.get_public_key()),
+        keypair_a,
+        MassaTime::from_millis(1000),
+        MassaTime::from_millis(1000),
+        version_a,
+        ConnectionId(0),
+        f64::INFINITY,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
+    );
+    let handshake_b = HandshakeWorker::spawn(
+        read_b,
+        write_b,
+        NodeId::new(keypair_b.get_public_key()),
+        keypair_b,
+        MassaTime::from_millis(1000),
+        MassaTime::from_millis(1000),
+        version_b,
+        ConnectionId(1),
+        f64::INFINITY,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
+    );
+
+    match handshake_a.await.unwrap().1 {
+        Err(NetworkError::IncompatibleVersion(local, remote)) => {
+            assert_eq!(local, version_a);
+            assert_eq!(remote, version_b);
+        }
+        Err(err) => panic!("unexpected handshake error: {}", err),
+        Ok(_) => panic!("handshake with an incompatible version succeeded"),
+    }
+    match handshake_b.await.unwrap().1 {
+        Err(NetworkError::IncompatibleVersion(local, remote)) => {
+            assert_eq!(local, version_b);
+            assert_eq!(remote, version_a);
+        }
+        Err(err) => panic!("unexpected handshake error: {}", err),
+        Ok(_) => panic!("handshake with an incompatible version succeeded"),
+    }
+}
+
+/// Test that large messages sent by a write binder with compression enabled
+/// are read back identical by a read binder with compression enabled.
+#[tokio::test]
+#[serial]
+async fn test_binders_compression_roundtrip() {
+    let (duplex_a, duplex_b) = tokio::io::duplex(65536);
+    let (_read_a, write_a) = tokio::io::split(duplex_a);
+    let (read_b, _write_b) = tokio::io::split(duplex_b);
+    let mut writer = WriteBinder::new(write_a, f64::INFINITY, MAX_MESSAGE_SIZE);
+    let mut reader = ReadBinder::new(
+        read_b,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        MAX_MESSAGE_SIZE,
+        MessageDeserializer::new(
+            THREAD_COUNT,
+            ENDORSEMENT_COUNT,
+            MAX_ADVERTISE_LENGTH,
+            MAX_ASK_BLOCKS_PER_MESSAGE,
+            MAX_OPERATIONS_PER_BLOCK,
+            MAX_OPERATIONS_PER_MESSAGE,
+            MAX_ENDORSEMENTS_PER_MESSAGE,
+            MAX_DATASTORE_VALUE_LENGTH,
+            MAX_FUNCTION_NAME_LENGTH,
+            MAX_PARAMETERS_SIZE,
+            MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+            MAX_OPERATION_DATASTORE_KEY_LENGTH,
+            MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        ),
+    );
+    writer.enable_compression(1024);
+    reader.enable_compression();
+
+    // a large message, compressed, and a small one, sent as is
+    let operations = (0..100).map(|i| get_transaction(50, i)).collect();
+    let messages = [Message::Operations(operations), Message::AskPeerList];
+    let serializer = MessageSerializer::new();
+    for msg in messages.iter() {
+        writer.send(msg).await.unwrap();
+        let (_, received) = tokio::time::timeout(Duration::from_millis(1000), reader.next())
+            .await
+            .expect("timeout while reading compressed messages")
+            .unwrap()
+            .unwrap();
+        let (mut sent_bytes, mut received_bytes) = (Vec::new(), Vec::new());
+        serializer.serialize(msg, &mut sent_bytes).unwrap();
+        serializer
+            .serialize(&received, &mut received_bytes)
+            .unwrap();
+        assert_eq!(sent_bytes, received_bytes);
+    }
+}
+
+/// Test that asking for operations by their full ids goes through the binders unchanged.
+#[tokio::test]
+#[serial]
+async fn test_binders_ask_for_operations_by_id() {
+    let (duplex_a, duplex_b) = tokio::io::duplex(65536);
+    let (_read_a, write_a) = tokio::io::split(duplex_a);
+    let (read_b, _write_b) = tokio::io::split(duplex_b);
+    let mut writer = WriteBinder::new(write_a, f64::INFINITY, MAX_MESSAGE_SIZE);
+    let mut reader = ReadBinder::new(
+        read_b,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        MAX_MESSAGE_SIZE,
+        MessageDeserializer::new(
+            THREAD_COUNT,
+            ENDORSEMENT_COUNT,
+            MAX_ADVERTISE_LENGTH,
+            MAX_ASK_BLOCKS_PER_MESSAGE,
+            MAX_OPERATIONS_PER_BLOCK,
+            MAX_OPERATIONS_PER_MESSAGE,
+            MAX_ENDORSEMENTS_PER_MESSAGE,
+            MAX_DATASTORE_VALUE_LENGTH,
+            MAX_FUNCTION_NAME_LENGTH,
+            MAX_PARAMETERS_SIZE,
+            MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+            MAX_OPERATION_DATASTORE_KEY_LENGTH,
+            MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        ),
+    );
+
+    let operation_ids: Vec<_> = (0..10).map(|i| get_transaction(50, i).id).collect();
+    writer
+        .send(&Message::AskForOperationsById(operation_ids.clone()))
+        .await
+        .unwrap();
+    let (_, received) = tokio::time::timeout(Duration::from_millis(1000), reader.next())
+        .await
+        .expect("timeout while reading message")
+        .unwrap()
+        .unwrap();
+    match received {
+        Message::AskForOperationsById(received_ids) => assert_eq!(received_ids, operation_ids),
+        _ => panic!("unexpected message"),
+    }
+}
+
+/// Test that compression is only used when both nodes announced it during the handshake.
+#[tokio::test]
+#[serial]
+async fn test_handshake_compression_negotiation() {
+    for (compression_a, compression_b) in [(Some(0), Some(0)), (Some(0), None)] {
+        let (duplex_a, duplex_b) = tokio::io::duplex(65536);
+        let (read_a, write_a) = tokio::io::split(duplex_a);
+        let (read_b, write_b) = tokio::io::split(duplex_b);
+        let keypair_a = KeyPair::generate();
+        let keypair_b = KeyPair::generate();
+        let handshake_a = HandshakeWorker::spawn(
+            read_a,
+            write_a,
+            NodeId::new(keypair_a.get_public_key()),
+            keypair_a,
+            MassaTime::from_millis(1000),
+            MassaTime::from_millis(1000),
+            Version::from_str("TEST.1.10").unwrap(),
+            ConnectionId(0),
+            f64::INFINITY,
+            f64::INFINITY,
+            MAX_MESSAGE_SIZE,
+            compression_a,
+        );
+        let handshake_b = HandshakeWorker::spawn(
+            read_b,
+            write_b,
+            NodeId::new(keypair_b.get_public_key()),
+            keypair_b,
+            MassaTime::from_millis(1000),
+            MassaTime::from_millis(1000),
+            Version::from_str("TEST.1.10").unwrap(),
+            ConnectionId(1),
+            f64::INFINITY,
+            f64::INFINITY,
+            MAX_MESSAGE_SIZE,
+            compression_b,
+        );
+        let (_, _reader_a, mut writer_a) = handshake_a.await.unwrap().1.unwrap();
+        let (_, mut reader_b, _writer_b) = handshake_b.await.unwrap().1.unwrap();
+
+        let peers = vec![IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11))];
+        writer_a
+            .send(&Message::PeerList(peers.clone()))
+            .await
+            .unwrap();
+        match tokio::time::timeout(Duration::from_millis(1000), reader_b.next())
+            .await
+            .expect("timeout while reading the message sent after the handshake")
+            .unwrap()
+        {
+            Some((_, Message::PeerList(received))) => assert_eq!(received, peers),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+}
+
+/// Test that a peer stalling in the middle of the handshake is dropped
+/// at the overall handshake deadline, before the timeout of the stalled read.
+#[tokio::test]
+#[serial]
+async fn test_handshake_overall_timeout() {
+    let (duplex_a, duplex_b) = tokio::io::duplex(65536);
+    let (read_a, write_a) = tokio::io::split(duplex_a);
+    let (_read_b, write_b) = tokio::io::split(duplex_b);
+    let keypair_a = KeyPair::generate();
+    let keypair_b = KeyPair::generate();
+    let start = Instant::now();
+    let handshake_a = HandshakeWorker::spawn(
+        read_a,
+        write_a,
+        NodeId::new(keypair_a.get_public_key()),
+        keypair_a,
+        MassaTime::from_millis(1000),
+        MassaTime::from_millis(300),
+        Version::from_str("TEST.1.10").unwrap(),
+        ConnectionId(0),
+        f64::INFINITY,
+        f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
+    );
+
+    // the peer sends its handshake initiation in time, then never replies
+    sleep(Duration::from_millis(200)).await;
+    let mut writer_b = WriteBinder::new(write_b, f64::INFINITY, MAX_MESSAGE_SIZE);
+    writer_b
+        .send(&Message::HandshakeInitiation {
+            public_key: keypair_b.get_public_key(),
+            random_bytes: [0u8; 32],
+            version: Version::from_str("TEST.1.10").unwrap(),
+            compression: false,
+        })
+        .await
+        .unwrap();
+
+    match handshake_a.await.unwrap().1 {
+        Err(NetworkError::HandshakeError(HandshakeErrorType::HandshakeTimeout)) => {}
+        Err(err) => panic!("unexpected handshake error: {}", err),
+        Ok(_) => panic!("handshake should not succeed"),
+    }
+    assert!(start.elapsed() < Duration::from_millis(1000));
+}
+
+/// Test that draining the peer database below `low_peer_count_threshold`
+/// emits a single `NetworkEvent::LowPeerCount`.
+#[tokio::test]
+#[serial]
+async fn test_low_peer_count_event() {
+    let bind_port: u16 = 50_000;
+    let mock_ip = IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11));
+    let temp_peers_file = super::tools::generate_peers_file(&[PeerInfo::new(mock_ip, true)]);
+    let network_conf = NetworkConfig {
+        wakeup_interval: 200.into(),
+        low_peer_count_threshold: 1,
+        peer_types_config: default_testing_peer_type_enum_map(),
+        ..NetworkConfig::scenarios_default(bind_port, temp_peers_file.path())
+    };
+
+    tools::network_test(
+        network_conf.clone(),
+        temp_peers_file,
+        async move |network_command_sender,
+                    mut network_event_receiver,
+                    network_manager,
+                    mock_interface| {
+            let low_peer_count = |evt: NetworkEvent| match evt {
+                NetworkEvent::LowPeerCount { current, target } => Some((current, target)),
+                _ => None,
+            };
+
+            // the only known peer keeps the count at the threshold
+            assert_eq!(
+                tools::wait_network_event(&mut network_event_receiver, 1000.into(), low_peer_count)
+                    .await,
+                None
+            );
+
+            // banning it drains the peer database
+            network_command_sender
+                .node_ban_by_ips(vec![mock_ip])
+                .await
+                .expect("error during send ban command.");
+            assert_eq!(
+                tools::wait_network_event(&mut network_event_receiver, 1000.into(), low_peer_count)
+                    .await,
+                Some((0, 1))
+            );
+
+            // the event is not repeated while the count stays low
+            assert_eq!(
+                tools::wait_network_event(&mut network_event_receiver, 1000.into(), low_peer_count)
+                    .await,
+                None
+            );
+
+            (
+                network_event_receiver,
+                network_manager,
+                mock_interface,
+                vec![],
+            )
+        },
+    )
+    .await;
+}
+
+/// Test that a peer list longer than `max_peer_advertise_length` is truncated before being merged.
+#[tokio::test]
+#[serial]
+async fn test_peer_list_truncated() {
+    let bind_port: u16 = 50_000;
+    let mock_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11)), bind_port);
+    let temp_peers_file = super::tools::generate_peers_file(&[PeerInfo {
+        ip: mock_addr.ip(),
+        peer_type: PeerType::Bootstrap,
+        last_alive: None,
+        last_failure: None,
+        advertised: true,
+        active_out_connection_attempts: 0,
+        active_out_connections: 0,
+        active_in_connections: 0,
+        banned: false,
+    }]);
+    let network_conf = NetworkConfig {
+        peer_types_config: default_testing_peer_type_enum_map(),
+        max_peer_advertise_length: 2,
+        ..NetworkConfig::scenarios_default(bind_port, temp_peers_file.path())
+    };
+
+    tools::network_test(
+        network_conf.clone(),
+        temp_peers_file,
+        async move |network_command_sender,
+                    mut network_event_receiver,
+                    network_manager,
+                    mut mock_interface| {
+            let (_conn1_id, conn1_r, mut conn1_w) = tools::full_connection_from_controller(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(0),
+            )
+            .await;
+            let conn1_drain = tools::incoming_message_drain_start(conn1_r).await;
+
+            // send a peer list twice as long as the limit
+            let advertised: Vec<IpAddr> = (21..25)
+                .map(|i| IpAddr::V4(Ipv4Addr::new(169, 202, 0, i)))
+                .collect();
+            conn1_w
+                .send(&Message::PeerList(advertised.clone()))
+                .await
+                .unwrap();
+            sleep(Duration::from_millis(500)).await;
+
+            // only the first ips of the list are merged
+            let peers = network_command_sender.get_peers().await.unwrap().peers;
+            assert!(advertised[..2].iter().all(|ip| peers.contains_key(ip)));
+            assert!(advertised[2..].iter().all(|ip| !peers.contains_key(ip)));
+
+            (
+                network_event_receiver,
+                network_manager,
+                mock_interface,
+                vec![conn1_drain],
+            )
+        },
+    )
+    .await;
+}
+
+/// Test that peer lists sent before `min_peer_list_interval` are dropped,
+/// and that a node repeating it `max_peer_list_violations` times is banned.
+#[tokio::test]
+#[serial]
+async fn test_peer_list_rate_limited() {
+    let bind_port: u16 = 50_000;
+    let mock_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11)), bind_port);
+    let temp_peers_file = super::tools::generate_peers_file(&[PeerInfo {
+        ip: mock_addr.ip(),
+        peer_type: PeerType::Bootstrap,
+        last_alive: None,
+        last_failure: None,
+        advertised: true,
+        active_out_connection_attempts: 0,
+        active_out_connections: 0,
+        active_in_connections: 0,
+        banned: false,
+    }]);
+    let network_conf = NetworkConfig {
+        peer_types_config: default_testing_peer_type_enum_map(),
+        min_peer_list_interval: MassaTime::from_millis(60_000),
+        max_peer_list_violations: 2,
+        ..NetworkConfig::scenarios_default(bind_port, temp_peers_file.path())
+    };
+
+    tools::network_test(
+        network_conf.clone(),
+        temp_peers_file,
+        async move |network_command_sender,
+                    mut network_event_receiver,
+                    network_manager,
+                    mut mock_interface| {
+            let (conn1_id, conn1_r, mut conn1_w) = tools::full_connection_from_controller(
+                &mut network_event_receiver,
+                &mut mock_interface,
+                mock_addr,
+                1_000u64,
+                1_000u64,
+                1_000u64,
+                ConnectionId(0),
+            )
+            .await;
+            let conn1_drain = tools::incoming_message_drain_start(conn1_r).await;
+
+            // the first list is accepted, the two next ones are rate limited
+            let advertised: Vec<IpAddr> = (21..24)
+                .map(|i| IpAddr::V4(Ipv4Addr::new(169, 202, 0, i)))
+                .collect();
+            for ip in &advertised {
+                conn1_w.send(&Message::PeerList(vec![*ip])).await.unwrap();
+            }
+
+            // the second violation bans the node
+            if let Some(node) =
+                tools::wait_network_event(&mut network_event_receiver, 1000.into(), |msg| match msg
+                {
+                    NetworkEvent::ConnectionClosed(node) => Some(node),
+                    _ => None,
+                })
+                .await
+            {
+                assert_eq!(node, conn1_id);
+            } else {
+                panic!("Timeout while waiting for connection closed event");
+            }
+
+            let peers = network_command_sender.get_peers().await.unwrap().peers;
+            assert!(peers.contains_key(&advertised[0]));
+            assert!(advertised[1..].iter().all(|ip| !peers.contains_key(ip)));
+
+            (
+                network_event_receiver,
+                network_manager,
+                mock_interface,
+                vec![conn1_drain],
+            )
+        },
+    )
+    .await;
+}
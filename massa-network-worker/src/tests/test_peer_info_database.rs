@@ -553,6 +553,55 @@ async fn test_get_advertisable_peer_ips() {
     );
 }
 
+/// Peers that can't be told apart by their timestamps must be ordered by IP,
+/// whatever the iteration order of the peer map, and the list must be capped.
+#[tokio::test]
+#[serial]
+async fn test_get_advertisable_peer_ips_ordering() {
+    let network_settings = NetworkConfig {
+        routable_ip: None,
+        max_peer_advertise_length: 5,
+        ..NetworkConfig::default()
+    };
+    let last_alive = Some(MassaTime::now().unwrap());
+    let build_db = |ips: &[u8]| {
+        let peers: HashMap<IpAddr, PeerInfo> = ips
+            .iter()
+            .map(|&i| {
+                let mut peer = default_peer_info_not_connected(IpAddr::V4(
+                    std::net::Ipv4Addr::new(169, 202, 0, i),
+                ));
+                peer.last_alive = last_alive;
+                (peer.ip, peer)
+            })
+            .collect();
+        let wakeup_interval = network_settings.wakeup_interval;
+        let (saver_watch_tx, _) = watch::channel(peers.clone());
+        PeerInfoDatabase {
+            network_settings: network_settings.clone(),
+            peers,
+            saver_join_handle: tokio::spawn(async move {}),
+            saver_watch_tx,
+            peer_types_connection_count: Default::default(),
+            wakeup_interval,
+        }
+    };
+
+    let ips: Vec<u8> = (1..=20).rev().collect();
+    let expected: Vec<IpAddr> = (1..=5)
+        .map(|i| IpAddr::V4(std::net::Ipv4Addr::new(169, 202, 0, i)))
+        .collect();
+    let db = build_db(&ips);
+    assert_eq!(db.get_advertisable_peer_ips(), expected);
+    assert_eq!(db.get_advertisable_peer_ips(), expected);
+    let mut shuffled_ips = ips;
+    shuffled_ips.rotate_left(7);
+    assert_eq!(
+        build_db(&shuffled_ips).get_advertisable_peer_ips(),
+        expected
+    );
+}
+
 #[tokio::test]
 #[serial]
 async fn test_get_out_connection_candidate_ips() {
@@ -10,6 +10,7 @@ use crate::NetworkError;
 use crate::NetworkEvent;
 
 use massa_hash::Hash;
+use massa_models::config::MAX_MESSAGE_SIZE;
 use massa_models::node::NodeId;
 use massa_models::secure_share::SecureShareContent;
 use massa_models::{
@@ -71,6 +72,31 @@ pub async fn full_connection_to_controller(
     event_timeout_ms: u64,
     rw_timeout_ms: u64,
     connection_id: ConnectionId,
+) -> (NodeId, ReadBinder, WriteBinder) {
+    full_connection_to_controller_with_keypair(
+        network_event_receiver,
+        mock_interface,
+        mock_addr,
+        connect_timeout_ms,
+        event_timeout_ms,
+        rw_timeout_ms,
+        connection_id,
+        KeyPair::generate(),
+    )
+    .await
+}
+
+/// Same as `full_connection_to_controller`, with the node identified by `keypair`
+#[allow(clippy::too_many_arguments)]
+pub async fn full_connection_to_controller_with_keypair(
+    network_event_receiver: &mut NetworkEventReceiver,
+    mock_interface: &mut MockEstablisherInterface,
+    mock_addr: SocketAddr,
+    connect_timeout_ms: u64,
+    event_timeout_ms: u64,
+    rw_timeout_ms: u64,
+    connection_id: ConnectionId,
+    keypair: KeyPair,
 ) -> (NodeId, ReadBinder, WriteBinder) {
     // establish connection towards controller
     let (mock_read_half, mock_write_half) = timeout(
@@ -82,7 +108,6 @@ pub async fn full_connection_to_controller(
     .expect("connection towards controller failed");
 
     // perform handshake
-    let keypair = KeyPair::generate();
     let mock_node_id = NodeId::new(keypair.get_public_key());
     let res = HandshakeWorker::spawn(
         mock_read_half,
@@ -90,10 +115,13 @@ pub async fn full_connection_to_controller(
         mock_node_id,
         keypair,
         rw_timeout_ms.into(),
+        rw_timeout_ms.into(),
         Version::from_str("TEST.1.10").unwrap(),
         connection_id,
         f64::INFINITY,
         f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
     )
     .await
     .expect("handshake creation failed")
@@ -149,10 +177,13 @@ pub async fn rejected_connection_to_controller(
         mock_node_id,
         keypair,
         rw_timeout_ms.into(),
+        rw_timeout_ms.into(),
         Version::from_str("TEST.1.10").unwrap(),
         connection_id,
         f64::INFINITY,
         f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
     )
     .await
     .expect("handshake creation failed")
@@ -234,10 +265,13 @@ pub async fn full_connection_from_controller(
         mock_node_id,
         keypair,
         rw_timeout_ms.into(),
+        rw_timeout_ms.into(),
         Version::from_str("TEST.1.10").unwrap(),
         connection_id,
         f64::INFINITY,
         f64::INFINITY,
+        MAX_MESSAGE_SIZE,
+        None,
     )
     .await
     .expect("handshake creation failed")
@@ -34,18 +34,21 @@ use massa_models::config::constants::{
     MAX_ASYNC_GAS, MAX_ASYNC_MESSAGE_DATA, MAX_ASYNC_POOL_LENGTH, MAX_BLOCK_SIZE,
     MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_BOOTSTRAP_BLOCKS, MAX_BOOTSTRAP_ERROR_LENGTH,
     MAX_BOOTSTRAP_FINAL_STATE_PARTS_SIZE, MAX_BOOTSTRAP_MESSAGE_SIZE, MAX_BYTECODE_LENGTH,
-    MAX_DATASTORE_ENTRY_COUNT, MAX_DATASTORE_KEY_LENGTH, MAX_DATASTORE_VALUE_LENGTH,
-    MAX_DEFERRED_CREDITS_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE, MAX_EXECUTED_OPS_CHANGES_LENGTH,
-    MAX_EXECUTED_OPS_LENGTH, MAX_FUNCTION_NAME_LENGTH, MAX_GAS_PER_BLOCK, MAX_LEDGER_CHANGES_COUNT,
-    MAX_MESSAGE_SIZE, MAX_OPERATIONS_PER_BLOCK, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
-    MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE,
-    MAX_PRODUCTION_STATS_LENGTH, MAX_ROLLS_COUNT_LENGTH, NETWORK_CONTROLLER_CHANNEL_SIZE,
-    NETWORK_EVENT_CHANNEL_SIZE, NETWORK_NODE_COMMAND_CHANNEL_SIZE, NETWORK_NODE_EVENT_CHANNEL_SIZE,
-    OPERATION_VALIDITY_PERIODS, PERIODS_PER_CYCLE, POOL_CONTROLLER_CHANNEL_SIZE,
-    POS_MISS_RATE_DEACTIVATION_THRESHOLD, POS_SAVED_CYCLES, PROTOCOL_CONTROLLER_CHANNEL_SIZE,
-    PROTOCOL_EVENT_CHANNEL_SIZE, ROLL_PRICE, T0, THREAD_COUNT, VERSION,
+    MAX_CALL_DEPTH, MAX_COMPILE_GAS, MAX_DATASTORE_ENTRY_COUNT, MAX_DATASTORE_KEY_LENGTH,
+    MAX_DATASTORE_SIZE_PER_ADDRESS, MAX_DATASTORE_VALUE_LENGTH, MAX_DEFERRED_CREDITS_LENGTH,
+    MAX_ENDORSEMENTS_PER_MESSAGE, MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH,
+    MAX_FUNCTION_NAME_LENGTH, MAX_GAS_PER_BLOCK, MAX_LEDGER_CHANGES_COUNT, MAX_MESSAGE_SIZE,
+    MAX_OPERATIONS_PER_BLOCK, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+    MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+    MAX_OWNED_ADDRESSES_PER_CALL, MAX_PARAMETERS_SIZE, MAX_PRODUCTION_STATS_LENGTH,
+    MAX_ROLLS_COUNT_LENGTH, NETWORK_CONTROLLER_CHANNEL_SIZE, NETWORK_EVENT_CHANNEL_SIZE,
+    NETWORK_NODE_COMMAND_CHANNEL_SIZE, NETWORK_NODE_EVENT_CHANNEL_SIZE, OPERATION_VALIDITY_PERIODS,
+    PERIODS_PER_CYCLE, POOL_CONTROLLER_CHANNEL_SIZE, POS_MISS_RATE_DEACTIVATION_THRESHOLD,
+    POS_SAVED_CYCLES, PROTOCOL_CONTROLLER_CHANNEL_SIZE, PROTOCOL_EVENT_CHANNEL_SIZE, ROLL_PRICE,
+    T0, THREAD_COUNT, VERSION,
 };
 use massa_models::config::CONSENSUS_BOOTSTRAP_PART_SIZE;
+use massa_models::timeslots::check_genesis_timestamp;
 use massa_network_exports::{Establisher, NetworkConfig, NetworkManager};
 use massa_network_worker::start_network_controller;
 use massa_pool_exports::{PoolChannels, PoolConfig, PoolManager};
@@ -246,6 +249,7 @@ async fn launch(
         routable_ip: SETTINGS.network.routable_ip,
         protocol_port: SETTINGS.network.protocol_port,
         connect_timeout: SETTINGS.network.connect_timeout,
+        handshake_timeout: SETTINGS.network.handshake_timeout,
         wakeup_interval: SETTINGS.network.wakeup_interval,
         initial_peers_file: SETTINGS.network.initial_peers_file.clone(),
         peers_file: SETTINGS.network.peers_file.clone(),
@@ -253,6 +257,7 @@ async fn launch(
         peer_types_config: SETTINGS.network.peer_types_config.clone(),
         max_in_connections_per_ip: SETTINGS.network.max_in_connections_per_ip,
         max_idle_peers: SETTINGS.network.max_idle_peers,
+        low_peer_count_threshold: SETTINGS.network.low_peer_count_threshold,
         max_banned_peers: SETTINGS.network.max_banned_peers,
         peers_file_dump_interval: SETTINGS.network.peers_file_dump_interval,
         message_timeout: SETTINGS.network.message_timeout,
@@ -269,9 +274,13 @@ async fn launch(
         max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
         thread_count: THREAD_COUNT,
         endorsement_count: ENDORSEMENT_COUNT,
-        max_peer_advertise_length: MAX_ADVERTISE_LENGTH,
+        max_peer_advertise_length: SETTINGS.network.max_advertise_length,
+        min_peer_list_interval: SETTINGS.network.min_peer_list_interval,
+        max_peer_list_violations: SETTINGS.network.max_peer_list_violations,
         max_endorsements_per_message: MAX_ENDORSEMENTS_PER_MESSAGE,
         max_message_size: MAX_MESSAGE_SIZE,
+        max_incoming_message_size: SETTINGS.network.max_incoming_message_size,
+        message_compression_threshold: SETTINGS.network.message_compression_threshold,
         max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
         max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -331,14 +340,27 @@ async fn launch(
         max_datastore_key_length: MAX_DATASTORE_KEY_LENGTH,
         max_bytecode_size: MAX_BYTECODE_LENGTH,
         max_datastore_value_size: MAX_DATASTORE_VALUE_LENGTH,
+        max_owned_addresses: MAX_OWNED_ADDRESSES_PER_CALL,
+        max_events_per_slot: MAX_EVENTS_PER_SLOT,
+        max_event_data_size_per_slot: MAX_EVENT_DATA_SIZE_PER_SLOT,
+        max_datastore_size_per_address: MAX_DATASTORE_SIZE_PER_ADDRESS,
         max_module_cache_size: SETTINGS.execution.max_module_cache_size,
+        max_compile_gas: MAX_COMPILE_GAS,
+        max_call_depth: MAX_CALL_DEPTH,
         storage_costs_constants,
         max_read_only_gas: SETTINGS.execution.max_read_only_gas,
+        emit_roll_change_events: SETTINGS.execution.emit_roll_change_events,
         gas_costs: GasCosts::new(
             SETTINGS.execution.abi_gas_costs_file.clone(),
             SETTINGS.execution.wasm_gas_costs_file.clone(),
         )
         .expect("Failed to load gas costs"),
+        denied_contract_addresses: SETTINGS
+            .execution
+            .denied_contract_addresses
+            .iter()
+            .copied()
+            .collect(),
     };
     let (execution_manager, execution_controller) = start_execution_worker(
         execution_config,
@@ -359,6 +381,7 @@ async fn launch(
         channels_size: POOL_CONTROLLER_CHANNEL_SIZE,
         broadcast_enabled: SETTINGS.api.enable_ws,
         broadcast_operations_capacity: SETTINGS.pool.broadcast_operations_capacity,
+        min_fee: SETTINGS.pool.min_fee,
     };
 
     let pool_channels = PoolChannels {
@@ -439,6 +462,10 @@ async fn launch(
         max_simultaneous_ask_blocks_per_node: SETTINGS
             .protocol
             .max_simultaneous_ask_blocks_per_node,
+        max_simultaneous_ask_blocks: SETTINGS.protocol.max_simultaneous_ask_blocks,
+        block_retrieval_attempts_before_backoff: SETTINGS
+            .protocol
+            .block_retrieval_attempts_before_backoff,
         max_send_wait: SETTINGS.protocol.max_send_wait,
         operation_batch_buffer_capacity: SETTINGS.protocol.operation_batch_buffer_capacity,
         operation_announcement_buffer_capacity: SETTINGS
@@ -483,6 +510,9 @@ async fn launch(
         genesis_timestamp: *GENESIS_TIMESTAMP,
         t0: T0,
         initial_delay: SETTINGS.factory.initial_delay,
+        min_connections: SETTINGS.factory.min_connections,
+        endorsement_jitter: SETTINGS.factory.endorsement_jitter,
+        endorsement_lateness_cutoff: SETTINGS.factory.endorsement_lateness_cutoff,
         max_block_size: MAX_BLOCK_SIZE as u64,
         max_block_gas: MAX_GAS_PER_BLOCK,
     };
@@ -537,6 +567,7 @@ async fn launch(
         genesis_timestamp: *GENESIS_TIMESTAMP,
         t0: T0,
         periods_per_cycle: PERIODS_PER_CYCLE,
+        min_fee: SETTINGS.pool.min_fee,
     };
 
     // spawn Massa API
@@ -786,6 +817,14 @@ async fn run(args: Args) -> anyhow::Result<()> {
         std::process::exit(1);
     }));
 
+    // refuse to start with a genesis timestamp that does not match the current time
+    check_genesis_timestamp(
+        *GENESIS_TIMESTAMP,
+        MassaTime::now()?,
+        SETTINGS.consensus.genesis_timestamp_tolerance,
+        SETTINGS.consensus.allow_old_genesis_timestamp,
+    )?;
+
     // load or create wallet, asking for password if necessary
     let node_wallet = load_wallet(args.password, &SETTINGS.factory.staking_wallet_path)?;
 
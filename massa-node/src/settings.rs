@@ -5,7 +5,7 @@ use std::path::PathBuf;
 
 use enum_map::EnumMap;
 use massa_bootstrap::IpType;
-use massa_models::{config::build_massa_settings, node::NodeId};
+use massa_models::{address::Address, amount::Amount, config::build_massa_settings, node::NodeId};
 use massa_time::MassaTime;
 use serde::Deserialize;
 use std::net::{IpAddr, SocketAddr};
@@ -31,6 +31,8 @@ pub struct ExecutionSettings {
     pub abi_gas_costs_file: PathBuf,
     pub wasm_gas_costs_file: PathBuf,
     pub max_module_cache_size: u32,
+    pub emit_roll_change_events: bool,
+    pub denied_contract_addresses: Vec<Address>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -52,6 +54,7 @@ pub struct NetworkSettings {
     pub routable_ip: Option<IpAddr>,
     pub protocol_port: u16,
     pub connect_timeout: MassaTime,
+    pub handshake_timeout: MassaTime,
     pub wakeup_interval: MassaTime,
     pub initial_peers_file: PathBuf,
     pub peers_file: PathBuf,
@@ -59,10 +62,16 @@ pub struct NetworkSettings {
     pub peer_types_config: EnumMap<PeerType, PeerTypeConnectionConfig>,
     pub max_in_connections_per_ip: usize,
     pub max_idle_peers: usize,
+    pub low_peer_count_threshold: usize,
     pub max_banned_peers: usize,
+    pub max_advertise_length: u32,
     pub peers_file_dump_interval: MassaTime,
     pub message_timeout: MassaTime,
     pub ask_peer_list_interval: MassaTime,
+    pub min_peer_list_interval: MassaTime,
+    pub max_peer_list_violations: u32,
+    pub max_incoming_message_size: u32,
+    pub message_compression_threshold: Option<u32>,
     pub max_send_wait_node_event: MassaTime,
     pub max_send_wait_network_event: MassaTime,
     pub ban_timeout: MassaTime,
@@ -103,6 +112,12 @@ pub struct BootstrapSettings {
 pub struct FactorySettings {
     /// Initial delay
     pub initial_delay: MassaTime,
+    /// Minimal number of established connections before starting production
+    pub min_connections: usize,
+    /// Endorsement production jitter
+    pub endorsement_jitter: MassaTime,
+    /// Endorsement production lateness cutoff
+    pub endorsement_lateness_cutoff: MassaTime,
     /// Staking wallet file
     pub staking_wallet_path: PathBuf,
 }
@@ -116,6 +131,8 @@ pub struct PoolSettings {
     pub max_item_return_count: usize,
     /// operations sender(channel) capacity
     pub broadcast_operations_capacity: usize,
+    /// minimum fee an operation must pay to be accepted
+    pub min_fee: Amount,
 }
 
 /// API and server configuration, read from a file configuration.
@@ -176,6 +193,10 @@ pub struct ConsensusSettings {
     pub block_db_prune_interval: MassaTime,
     /// max number of items returned while querying
     pub max_item_return_count: usize,
+    /// max distance between the current time at startup and the genesis timestamp, in either direction (0 to disable)
+    pub genesis_timestamp_tolerance: MassaTime,
+    /// accept a genesis timestamp further than `genesis_timestamp_tolerance` in the past, for networks running for longer than that
+    pub allow_old_genesis_timestamp: bool,
     /// blocks headers sender(channel) capacity
     pub broadcast_blocks_headers_capacity: usize,
     /// blocks sender(channel) capacity
@@ -205,6 +226,10 @@ pub struct ProtocolSettings {
     pub max_node_known_endorsements_size: usize,
     /// we ask for the same block `max_simultaneous_ask_blocks_per_node` times at the same time
     pub max_simultaneous_ask_blocks_per_node: usize,
+    /// max number of blocks we ask for at the same time, all nodes included
+    pub max_simultaneous_ask_blocks: usize,
+    /// number of failed retrievals of a wanted block after which its next retrievals are delayed with an exponential backoff
+    pub block_retrieval_attempts_before_backoff: u32,
     /// Max wait time for sending a Network or Node event.
     pub max_send_wait: MassaTime,
     /// Maximum number of batches in the memory buffer.
@@ -28,4 +28,6 @@ pub struct PoolConfig {
     pub broadcast_enabled: bool,
     /// operations sender(channel) capacity
     pub broadcast_operations_capacity: usize,
+    /// minimum fee an operation must pay to be accepted in the pool
+    pub min_fee: Amount,
 }
@@ -2,6 +2,7 @@
 
 use massa_models::{
     block_id::BlockId, endorsement::EndorsementId, operation::OperationId, slot::Slot,
+    stats::PoolStats,
 };
 use massa_storage::Storage;
 
@@ -32,12 +33,20 @@ pub trait PoolController: Send + Sync {
     /// Get the number of operations in the pool
     fn get_operation_count(&self) -> usize;
 
+    /// Get statistics on the size and composition of the pool
+    fn get_pool_stats(&self) -> PoolStats;
+
     /// Check if the pool contains a list of endorsements. Returns one boolean per item.
     fn contains_endorsements(&self, endorsements: &[EndorsementId]) -> Vec<bool>;
 
     /// Check if the pool contains a list of operations. Returns one boolean per item.
     fn contains_operations(&self, operations: &[OperationId]) -> Vec<bool>;
 
+    /// Remove all operations from the pool, and all endorsements if `clear_endorsements` is set.
+    /// Items still waiting to be added asynchronously are not affected.
+    /// Returns the total number of removed items.
+    fn clear(&mut self, clear_endorsements: bool) -> usize;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn PoolController>`.
     fn clone_box(&self) -> Box<dyn PoolController>;
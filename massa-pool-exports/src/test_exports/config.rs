@@ -1,5 +1,6 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_models::amount::Amount;
 use massa_models::config::{
     ENDORSEMENT_COUNT, MAX_BLOCK_SIZE, MAX_GAS_PER_BLOCK, OPERATION_VALIDITY_PERIODS, ROLL_PRICE,
     THREAD_COUNT,
@@ -21,6 +22,7 @@ impl Default for PoolConfig {
             channels_size: 1024,
             broadcast_enabled: false,
             broadcast_operations_capacity: 5000,
+            min_fee: Amount::zero(),
         }
     }
 }
@@ -7,6 +7,7 @@ use std::sync::{
 
 use massa_models::{
     block_id::BlockId, endorsement::EndorsementId, operation::OperationId, slot::Slot,
+    stats::PoolStats,
 };
 use massa_storage::Storage;
 use massa_time::MassaTime;
@@ -71,10 +72,17 @@ pub enum MockPoolControllerMessage {
         /// Response channel
         response_tx: mpsc::Sender<Vec<bool>>,
     },
+    /// Clear the pool
+    Clear {
+        /// Whether endorsements are cleared too
+        clear_endorsements: bool,
+        /// Response channel
+        response_tx: mpsc::Sender<usize>,
+    },
     /// Get stats of the pool
     GetStats {
         /// Response channel
-        response_tx: mpsc::Sender<(usize, usize)>,
+        response_tx: mpsc::Sender<PoolStats>,
     },
     /// Notify that periods became final
     NotifyFinalCsPeriods {
@@ -190,6 +198,29 @@ impl PoolController for MockPoolController {
         response_rx.recv().unwrap()
     }
 
+    fn clear(&mut self, clear_endorsements: bool) -> usize {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .unwrap()
+            .send(MockPoolControllerMessage::Clear {
+                clear_endorsements,
+                response_tx,
+            })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
+    fn get_pool_stats(&self) -> PoolStats {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .unwrap()
+            .send(MockPoolControllerMessage::GetStats { response_tx })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
     fn contains_endorsements(&self, endorsements: &[EndorsementId]) -> Vec<bool> {
         let (response_tx, response_rx) = mpsc::channel();
         self.0
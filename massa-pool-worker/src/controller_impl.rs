@@ -4,6 +4,7 @@
 
 use massa_models::{
     block_id::BlockId, endorsement::EndorsementId, operation::OperationId, slot::Slot,
+    stats::PoolStats,
 };
 use massa_pool_exports::{PoolConfig, PoolController, PoolManager};
 use massa_storage::Storage;
@@ -139,6 +140,13 @@ impl PoolController for PoolControllerImpl {
         self.operation_pool.read().len()
     }
 
+    /// Get statistics on the size and composition of the pool
+    fn get_pool_stats(&self) -> PoolStats {
+        let mut stats = self.operation_pool.read().get_stats();
+        stats.endorsement_count = self.endorsement_pool.read().len() as u64;
+        stats
+    }
+
     /// Check if the pool contains a list of endorsements. Returns one boolean per item.
     fn contains_endorsements(&self, endorsements: &[EndorsementId]) -> Vec<bool> {
         let lck = self.endorsement_pool.read();
@@ -150,6 +158,16 @@ impl PoolController for PoolControllerImpl {
         let lck = self.operation_pool.read();
         operations.iter().map(|id| lck.contains(id)).collect()
     }
+
+    /// Remove all operations from the pool, and all endorsements if `clear_endorsements` is set.
+    fn clear(&mut self, clear_endorsements: bool) -> usize {
+        let mut removed = self.operation_pool.write().clear();
+        if clear_endorsements {
+            removed += self.endorsement_pool.write().clear();
+        }
+        info!("cleared {} items from the pool", removed);
+        removed
+    }
 }
 
 /// Implementation of the pool manager.
@@ -49,6 +49,21 @@ impl EndorsementPool {
         self.storage.get_endorsement_refs().contains(id)
     }
 
+    /// Remove all endorsements from the pool.
+    /// Returns the number of removed endorsements.
+    pub(crate) fn clear(&mut self) -> usize {
+        let removed: PreHashSet<EndorsementId> = self
+            .endorsements_indexed
+            .drain()
+            .map(|(_, id)| id)
+            .collect();
+        self.endorsements_sorted
+            .iter_mut()
+            .for_each(|endorsements| endorsements.clear());
+        self.storage.drop_endorsement_refs(&removed);
+        removed.len()
+    }
+
     /// notify of new final CS periods
     pub(crate) fn notify_final_cs_periods(&mut self, final_cs_periods: &[u64]) {
         // update internal final CS period counter
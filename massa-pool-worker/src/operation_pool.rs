@@ -4,9 +4,10 @@ use massa_execution_exports::ExecutionController;
 use massa_models::{
     address::Address,
     amount::Amount,
-    operation::OperationId,
+    operation::{OperationId, OperationTypeId},
     prehash::{CapacityAllocator, PreHashMap, PreHashSet},
     slot::Slot,
+    stats::PoolStats,
 };
 use massa_pool_exports::{PoolChannels, PoolConfig};
 use massa_storage::Storage;
@@ -69,6 +70,45 @@ impl OperationPool {
         self.operations.contains_key(id)
     }
 
+    /// Remove all operations from the pool.
+    /// Returns the number of removed operations.
+    pub(crate) fn clear(&mut self) -> usize {
+        let removed: PreHashSet<OperationId> =
+            self.operations.drain().map(|(id, _)| id).collect();
+        self.sorted_ops_per_thread.iter_mut().for_each(|ops| ops.clear());
+        self.ops_per_expiration.clear();
+        self.storage.drop_operation_refs(&removed);
+        removed.len()
+    }
+
+    /// Compute statistics on the operations of the pool.
+    /// The endorsement count is left at zero.
+    pub(crate) fn get_stats(&self) -> PoolStats {
+        let mut stats = PoolStats {
+            operation_count: self.operations.len() as u64,
+            ..Default::default()
+        };
+        let mut fees: Vec<Amount> = Vec::with_capacity(self.operations.len());
+        let ops = self.storage.read_operations();
+        for (id, op_info) in &self.operations {
+            stats.operation_bytes = stats.operation_bytes.saturating_add(op_info.size as u64);
+            fees.push(op_info.fee);
+            match ops.get(id).map(|op| op.content.op.kind()) {
+                Some(OperationTypeId::Transaction) => stats.transaction_count += 1,
+                Some(OperationTypeId::RollBuy) => stats.roll_buy_count += 1,
+                Some(OperationTypeId::RollSell) => stats.roll_sell_count += 1,
+                Some(OperationTypeId::ExecuteSC) => stats.execute_sc_count += 1,
+                Some(OperationTypeId::CallSC) => stats.call_sc_count += 1,
+                None => {}
+            }
+        }
+        fees.sort_unstable();
+        stats.min_fee = fees.first().copied();
+        stats.max_fee = fees.last().copied();
+        stats.median_fee = fees.get(fees.len() / 2).copied();
+        stats
+    }
+
     /// notify of new final slot
     pub(crate) fn notify_final_cs_periods(&mut self, final_cs_periods: &[u64]) {
         // update internal final slot counter
@@ -95,10 +135,13 @@ impl OperationPool {
         self.storage.drop_operation_refs(&removed_ops);
     }
 
-    /// Checks if an operation is relevant according to its thread and period validity range
+    /// Checks if an operation is relevant according to its thread and period validity range,
+    /// and if it pays at least the minimum fee
     pub(crate) fn is_operation_relevant(&self, op_info: &OperationInfo) -> bool {
         // too old
         *op_info.validity_period_range.end() > self.last_cs_final_periods[op_info.thread as usize]
+            // too cheap
+            && op_info.fee >= self.config.min_fee
         // todo check if validity not started yet
     }
 
@@ -57,6 +57,23 @@ fn test_add_irrelevant_operation() {
     });
 }
 
+/// Test that clearing the pool removes every operation and releases their references.
+#[test]
+fn test_clear_operations() {
+    operation_pool_test(PoolConfig::default(), |mut operation_pool, mut storage| {
+        storage.store_operations(create_some_operations(10, &KeyPair::generate(), 2));
+        operation_pool.add_operations(storage);
+        assert_eq!(operation_pool.len(), 10);
+        assert_eq!(operation_pool.clear(), 10);
+        assert_eq!(operation_pool.len(), 0);
+        assert_eq!(operation_pool.storage.get_op_refs().len(), 0);
+        assert!(operation_pool
+            .get_block_operations(&Slot::new(1, 0))
+            .0
+            .is_empty());
+    });
+}
+
 fn get_transaction(expire_period: u64, fee: u64) -> SecureShareOperation {
     let sender_keypair = KeyPair::generate();
 
@@ -74,6 +91,52 @@ fn get_transaction(expire_period: u64, fee: u64) -> SecureShareOperation {
     Operation::new_verifiable(content, OperationSerializer::new(), &sender_keypair).unwrap()
 }
 
+/// Test that operations paying less than the minimum fee are rejected.
+#[test]
+fn test_add_operation_below_min_fee() {
+    let pool_config = PoolConfig {
+        min_fee: Amount::from_str("10").unwrap(),
+        ..PoolConfig::default()
+    };
+    operation_pool_test(pool_config, |mut operation_pool, mut storage| {
+        let cheap_op = get_transaction(10, 9);
+        let op = get_transaction(10, 10);
+        let op_id = op.id;
+        storage.store_operations(vec![cheap_op, op]);
+        operation_pool.add_operations(storage);
+        assert_eq!(operation_pool.len(), 1);
+        assert!(operation_pool.contains(&op_id));
+    });
+}
+
+/// Test the size and composition statistics of the pool.
+#[test]
+fn test_operation_pool_stats() {
+    operation_pool_test(PoolConfig::default(), |mut operation_pool, mut storage| {
+        let stats = operation_pool.get_stats();
+        assert_eq!(stats.operation_count, 0);
+        assert_eq!(stats.median_fee, None);
+
+        let ops: Vec<SecureShareOperation> = [30, 10, 20]
+            .iter()
+            .map(|fee| get_transaction(10, *fee))
+            .collect();
+        let total_size: usize = ops.iter().map(|op| op.serialized_size()).sum();
+        storage.store_operations(ops);
+        operation_pool.add_operations(storage);
+
+        let stats = operation_pool.get_stats();
+        assert_eq!(stats.operation_count, 3);
+        assert_eq!(stats.operation_bytes, total_size as u64);
+        assert_eq!(stats.transaction_count, 3);
+        assert_eq!(stats.roll_buy_count, 0);
+        assert_eq!(stats.call_sc_count, 0);
+        assert_eq!(stats.min_fee, Some(Amount::from_str("10").unwrap()));
+        assert_eq!(stats.max_fee, Some(Amount::from_str("30").unwrap()));
+        assert_eq!(stats.median_fee, Some(Amount::from_str("20").unwrap()));
+    });
+}
+
 /// TODO refactor old tests
 #[test]
 #[ignore]
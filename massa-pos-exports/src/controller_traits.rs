@@ -49,6 +49,17 @@ pub trait SelectorController: Send + Sync {
     /// * `slot`: target slot of the selection
     fn get_selection(&self, slot: Slot) -> PosResult<Selection>;
 
+    /// Get the endorsement indices drawn for any of the given addresses at a slot,
+    /// along with the drawn address, in increasing index order.
+    /// # Arguments
+    /// * `slot`: target slot of the selection
+    /// * `addresses`: addresses to look for
+    fn get_endorsement_indices_for(
+        &self,
+        slot: Slot,
+        addresses: &[Address],
+    ) -> PosResult<Vec<(u32, Address)>>;
+
     /// Return a list of slots where `address` has been chosen to produce a
     /// block and a list where he is chosen for the endorsements.
     /// Look from the `start` slot to the `end` slot.
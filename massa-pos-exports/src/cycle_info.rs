@@ -165,7 +165,7 @@ impl CycleInfo {
         thread_count: u8,
     ) -> bool {
         let hash_computer = CycleInfoHashComputer::new();
-        let slots_per_cycle = periods_per_cycle.saturating_mul(thread_count as u64);
+        let slots_per_cycle = Slot::slots_per_cycle(periods_per_cycle, thread_count);
         let mut hash_concat: Vec<u8> = Vec::new();
 
         // compute cycle hash and concat
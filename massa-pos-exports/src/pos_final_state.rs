@@ -69,9 +69,7 @@ impl PoSFinalState {
     /// This should be called only if bootstrap did not happen.
     pub fn create_initial_cycle(&mut self) {
         let mut rng_seed = BitVec::with_capacity(
-            self.config
-                .periods_per_cycle
-                .saturating_mul(self.config.thread_count as u64)
+            Slot::slots_per_cycle(self.config.periods_per_cycle, self.config.thread_count)
                 .try_into()
                 .unwrap(),
         );
@@ -156,12 +154,10 @@ impl PoSFinalState {
         slot: Slot,
         feed_selector: bool,
     ) -> PosResult<()> {
-        let slots_per_cycle: usize = self
-            .config
-            .periods_per_cycle
-            .saturating_mul(self.config.thread_count as u64)
-            .try_into()
-            .unwrap();
+        let slots_per_cycle: usize =
+            Slot::slots_per_cycle(self.config.periods_per_cycle, self.config.thread_count)
+                .try_into()
+                .unwrap();
 
         // compute the current cycle from the given slot
         let cycle = slot.get_cycle(self.config.periods_per_cycle);
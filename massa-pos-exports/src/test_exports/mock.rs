@@ -169,6 +169,21 @@ impl SelectorController for MockSelectorController {
         response_rx.recv().unwrap()
     }
 
+    fn get_endorsement_indices_for(
+        &self,
+        slot: Slot,
+        addresses: &[Address],
+    ) -> PosResult<Vec<(u32, Address)>> {
+        Ok(self
+            .get_selection(slot)?
+            .endorsements
+            .into_iter()
+            .enumerate()
+            .filter(|(_, addr)| addresses.contains(addr))
+            .map(|(index, addr)| (index as u32, addr))
+            .collect())
+    }
+
     fn clone_box(&self) -> Box<dyn SelectorController> {
         Box::new(self.clone())
     }
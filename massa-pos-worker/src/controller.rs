@@ -100,6 +100,36 @@ impl SelectorController for SelectorControllerImpl {
             .ok_or(PosError::CycleUnavailable(cycle))
     }
 
+    /// Get the endorsement indices drawn for any of the given addresses at a slot,
+    /// along with the drawn address, in increasing index order.
+    /// # Arguments
+    /// * `slot`: target slot of the selection
+    /// * `addresses`: addresses to look for
+    fn get_endorsement_indices_for(
+        &self,
+        slot: Slot,
+        addresses: &[Address],
+    ) -> PosResult<Vec<(u32, Address)>> {
+        let cycle = slot.get_cycle(self.periods_per_cycle);
+        let (_cache_cv, cache_lock) = &*self.cache;
+        let cache_guard = cache_lock.read();
+        let cache = cache_guard.as_ref().map_err(|err| err.clone())?;
+
+        cache
+            .get(cycle)
+            .and_then(|selections| selections.draws.get(&slot))
+            .map(|selection| {
+                selection
+                    .endorsements
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, addr)| addresses.contains(addr))
+                    .map(|(index, addr)| (index as u32, *addr))
+                    .collect()
+            })
+            .ok_or(PosError::CycleUnavailable(cycle))
+    }
+
     /// Get [Address] of the selected block producer for a given slot
     /// # Arguments
     /// * `slot`: target slot of the selection
@@ -11,7 +11,10 @@ use massa_models::{
 use massa_network_exports::NetworkEventReceiver;
 use massa_storage::Storage;
 use serde::Serialize;
-use tokio::{sync::mpsc, task::JoinHandle};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
 use tracing::info;
 
 /// block result: map block id to
@@ -49,6 +52,8 @@ pub enum ProtocolCommand {
     PropagateOperations(Storage),
     /// Propagate endorsements
     PropagateEndorsements(Storage),
+    /// Get the number of nodes we have an established connection with
+    GetActiveNodeCount(oneshot::Sender<usize>),
 }
 
 /// protocol management commands
@@ -129,6 +134,20 @@ impl ProtocolCommandSender {
                 ProtocolError::ChannelError("propagate_endorsements command send error".into())
             })
     }
+
+    /// get the number of nodes we have an established connection with
+    pub fn get_active_node_count(&mut self) -> Result<usize, ProtocolError> {
+        massa_trace!("protocol.command_sender.get_active_node_count", {});
+        let (response_tx, response_rx) = oneshot::channel();
+        self.0
+            .blocking_send(ProtocolCommand::GetActiveNodeCount(response_tx))
+            .map_err(|_| {
+                ProtocolError::ChannelError("get_active_node_count command send error".into())
+            })?;
+        response_rx.blocking_recv().map_err(|_| {
+            ProtocolError::ChannelError("get_active_node_count response read error".into())
+        })
+    }
 }
 
 /// protocol manager used to stop the protocol
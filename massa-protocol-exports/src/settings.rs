@@ -25,6 +25,12 @@ pub struct ProtocolConfig {
     pub max_node_known_endorsements_size: usize,
     /// we ask for the same block `max_simultaneous_ask_blocks_per_node` times at the same time
     pub max_simultaneous_ask_blocks_per_node: usize,
+    /// max number of blocks we ask for at the same time, all nodes included.
+    /// Other wanted blocks are asked for when active requests complete or time out.
+    pub max_simultaneous_ask_blocks: usize,
+    /// number of failed retrievals (invalid or incomplete operations sent by nodes) of a wanted block
+    /// after which its next retrievals are delayed with an exponential backoff, starting at `ask_block_timeout`
+    pub block_retrieval_attempts_before_backoff: u32,
     /// Max wait time for sending a Network or Node event.
     pub max_send_wait: MassaTime,
     /// Maximum number of batches in the memory buffer.
@@ -13,7 +13,7 @@ use massa_models::{
     block_header::{BlockHeader, BlockHeaderSerializer},
     block_id::BlockId,
     endorsement::{Endorsement, EndorsementSerializerLW, SecureShareEndorsement},
-    operation::{Operation, OperationType, SecureShareOperation},
+    operation::{compute_operations_merkle_root, Operation, OperationType, SecureShareOperation},
     slot::Slot,
 };
 use massa_network_exports::{AskForBlocksInfo, NetworkCommand};
@@ -65,7 +65,7 @@ pub fn create_block(keypair: &KeyPair) -> SecureShareBlock {
                 BlockId(Hash::compute_from("Genesis 0".as_bytes())),
                 BlockId(Hash::compute_from("Genesis 1".as_bytes())),
             ],
-            operation_merkle_root: Hash::compute_from(&Vec::new()),
+            operation_merkle_root: compute_operations_merkle_root(&[]),
             endorsements: Vec::new(),
         },
         BlockHeaderSerializer::new(),
@@ -94,11 +94,8 @@ pub fn create_block_with_operations(
     slot: Slot,
     operations: Vec<SecureShareOperation>,
 ) -> SecureShareBlock {
-    let operation_merkle_root = Hash::compute_from(
-        &operations.iter().fold(Vec::new(), |acc, v| {
-            [acc, v.id.to_bytes().to_vec()].concat()
-        })[..],
-    );
+    let operation_merkle_root =
+        compute_operations_merkle_root(&operations.iter().map(|op| op.id).collect::<Vec<_>>());
     let header = BlockHeader::new_verifiable(
         BlockHeader {
             slot,
@@ -143,7 +140,7 @@ pub fn create_block_with_endorsements(
                 BlockId(Hash::compute_from("Genesis 0".as_bytes())),
                 BlockId(Hash::compute_from("Genesis 1".as_bytes())),
             ],
-            operation_merkle_root: Hash::compute_from(&Vec::new()),
+            operation_merkle_root: compute_operations_merkle_root(&[]),
             endorsements,
         },
         BlockHeaderSerializer::new(),
@@ -207,6 +204,8 @@ pub fn create_protocol_config() -> ProtocolConfig {
         max_node_known_blocks_size: 100,
         max_node_wanted_blocks_size: 100,
         max_simultaneous_ask_blocks_per_node: 10,
+        max_simultaneous_ask_blocks: 1024,
+        block_retrieval_attempts_before_backoff: 5,
         max_send_wait: MassaTime::from_millis(100),
         max_known_ops_size: 1000,
         max_node_known_ops_size: 1000,
@@ -6,25 +6,28 @@ use std::collections::hash_map::Entry;
 
 use crate::node_info::NodeInfo;
 use crate::protocol_worker::ProtocolWorker;
-use massa_hash::{Hash, HASH_SIZE_BYTES};
 use massa_logging::massa_trace;
 use massa_models::{
     block::{Block, BlockSerializer},
     block_header::SecuredHeader,
     block_id::BlockId,
     node::NodeId,
-    operation::{OperationId, SecureShareOperation},
+    operation::{compute_operations_merkle_root, OperationId, SecureShareOperation},
     prehash::{CapacityAllocator, PreHashSet},
-    secure_share::{Id, SecureShare},
+    secure_share::SecureShare,
 };
 use massa_network_exports::{AskForBlocksInfo, BlockInfoReply, NetworkEvent};
 use massa_protocol_exports::ProtocolError;
 use massa_serialization::Serializer;
 use massa_storage::Storage;
+use massa_time::TimeError;
 use std::pin::Pin;
 use tokio::time::{Instant, Sleep};
 use tracing::{info, warn};
 
+/// Maximal exponent of the backoff applied to the retrieval of blocks that repeatedly failed to be retrieved
+const MAX_BLOCK_RETRIEVAL_BACKOFF_EXPONENT: u32 = 6;
+
 // static tracing messages
 static NEW_CONN: &str = "protocol.protocol_worker.on_network_event.new_connection";
 static CONN_CLOSED: &str = "protocol.protocol_worker.on_network_event.connection_closed";
@@ -149,6 +152,20 @@ impl ProtocolWorker {
                 self.on_asked_operations_received(node, operation_prefix_ids)
                     .await?;
             }
+            NetworkEvent::ReceiveAskForOperationsById {
+                node,
+                operation_ids,
+            } => {
+                massa_trace!(ASKED_OPS, { "node": node, "operation_ids": operation_ids});
+                self.on_asked_operations_by_id_received(node, operation_ids)
+                    .await?;
+            }
+            NetworkEvent::LowPeerCount { current, target } => {
+                warn!(
+                    "peer starvation: {} advertisable peers known, expected at least {}",
+                    current, target
+                );
+            }
         }
         Ok(())
     }
@@ -344,15 +361,8 @@ impl ProtocolWorker {
             return Ok(());
         }
 
-        let mut total_hash: Vec<u8> =
-            Vec::with_capacity(operation_ids.len().saturating_mul(HASH_SIZE_BYTES));
-        operation_ids.iter().for_each(|op_id| {
-            let op_hash = op_id.get_hash().into_bytes();
-            total_hash.extend(op_hash);
-        });
-
         // Check operation_list against expected operations hash from header.
-        if header.content.operation_merkle_root == Hash::compute_from(&total_hash) {
+        if header.content.operation_merkle_root == compute_operations_merkle_root(&operation_ids) {
             // Add the ops of info.
             info.operation_ids = Some(operation_ids.clone());
             let known_operations = info.storage.claim_operation_refs(&operation_ids_set);
@@ -388,12 +398,51 @@ impl ProtocolWorker {
                     .await;
             }
         } else {
-            warn!("Node id {} sent us a operation list for block id {} but the hash in header doesn't match.", from_node_id, block_id);
+            let retrieval_failures = self.note_block_retrieval_failure(block_id)?;
+            warn!("Node id {} sent us a operation list for block id {} but the hash in header doesn't match (failed retrieval attempt {}).", from_node_id, block_id, retrieval_failures);
             let _ = self.ban_node(&from_node_id).await;
         }
         Ok(())
     }
 
+    /// Counts a failed retrieval attempt of a block of the wishlist.
+    /// Once `block_retrieval_attempts_before_backoff` is reached, the block stays in the wishlist
+    /// but its next retrievals are delayed: the delay starts at `ask_block_timeout`
+    /// and doubles with each further failure, up to `2^MAX_BLOCK_RETRIEVAL_BACKOFF_EXPONENT` times that.
+    ///
+    /// # Returns
+    /// The number of failed retrieval attempts of the block
+    fn note_block_retrieval_failure(&mut self, block_id: BlockId) -> Result<u32, ProtocolError> {
+        let ask_block_timeout = self.config.ask_block_timeout;
+        let attempts_before_backoff = self.config.block_retrieval_attempts_before_backoff;
+        let info = match self.block_wishlist.get_mut(&block_id) {
+            Some(info) => info,
+            None => return Ok(0),
+        };
+        info.retrieval_failures += 1;
+        let retrieval_failures = info.retrieval_failures;
+        if retrieval_failures >= attempts_before_backoff {
+            let exponent = std::cmp::min(
+                retrieval_failures - attempts_before_backoff,
+                MAX_BLOCK_RETRIEVAL_BACKOFF_EXPONENT,
+            );
+            let backoff = ask_block_timeout.checked_mul(1u64 << exponent)?;
+            info.next_retrieval_instant = Some(
+                Instant::now()
+                    .checked_add(backoff.into())
+                    .ok_or(TimeError::TimeOverflowError)?,
+            );
+            warn!(
+                "block {} failed to be retrieved {} times: delaying its next retrieval by {}",
+                block_id, retrieval_failures, backoff
+            );
+            let mut set = PreHashSet::<BlockId>::with_capacity(1);
+            set.insert(block_id);
+            self.remove_asked_blocks_of_node(&set)?;
+        }
+        Ok(retrieval_failures)
+    }
+
     /// Checks full block operations that we asked. (Because their was missing in the
     /// `checked_operations` cache variable, refer to `on_block_operation_list_received`)
     ///
@@ -418,9 +467,10 @@ impl ProtocolWorker {
             .note_operations_from_node(operations.clone(), &from_node_id, op_timer)
             .await
         {
+            let retrieval_failures = self.note_block_retrieval_failure(block_id)?;
             warn!(
-                "Node id {} sent us operations for block id {} but they failed at verifications. Err = {}",
-                from_node_id, block_id, err
+                "Node id {} sent us operations for block id {} but they failed at verifications (failed retrieval attempt {}). Err = {}",
+                from_node_id, block_id, retrieval_failures, err
             );
             let _ = self.ban_node(&from_node_id).await;
             return Ok(());
@@ -473,9 +523,10 @@ impl ProtocolWorker {
                         .mark_invalid_block(block_id, header);
                 } else {
                     if known_operations != block_ids_set {
+                        let retrieval_failures = self.note_block_retrieval_failure(block_id)?;
                         warn!(
-                            "Node id {} didn't sent us all the full operations for block id {}.",
-                            from_node_id, block_id
+                            "Node id {} didn't sent us all the full operations for block id {} (failed retrieval attempt {}).",
+                            from_node_id, block_id, retrieval_failures
                         );
                         if let Some(node) = self.active_nodes.get_mut(&from_node_id) && node.asked_blocks.contains_key(&block_id) {
                             node.asked_blocks.remove(&block_id);
@@ -103,6 +103,11 @@ pub(crate) struct BlockInfo {
     pub(crate) storage: Storage,
     /// Full operations size in bytes
     pub(crate) operations_size: usize,
+    /// Number of times a node sent us an invalid or incomplete operation list
+    /// or operation set for this block. The block is asked to another node after each failure.
+    pub(crate) retrieval_failures: u32,
+    /// Instant before which the block is not asked again, after repeated retrieval failures
+    pub(crate) next_retrieval_instant: Option<Instant>,
 }
 
 impl BlockInfo {
@@ -112,6 +117,8 @@ impl BlockInfo {
             operation_ids: None,
             storage,
             operations_size: 0,
+            retrieval_failures: 0,
+            next_retrieval_instant: None,
         }
     }
 }
@@ -512,6 +519,11 @@ impl ProtocolWorker {
             ProtocolCommand::PropagateEndorsements(endorsements) => {
                 self.propagate_endorsements(&endorsements).await;
             }
+            ProtocolCommand::GetActiveNodeCount(response_tx) => {
+                if response_tx.send(self.active_nodes.len()).is_err() {
+                    warn!("protocol: could not send get_active_node_count answer");
+                }
+            }
         }
         massa_trace!("protocol.protocol_worker.process_command.end", {});
         Ok(())
@@ -552,6 +564,13 @@ impl ProtocolWorker {
 
         // list blocks to re-ask and from whom
         for (hash, block_info) in self.block_wishlist.iter() {
+            // wait for the end of the backoff of blocks that failed to be retrieved too many times
+            if let Some(next_retrieval_instant) = block_info.next_retrieval_instant {
+                if next_retrieval_instant > now {
+                    next_tick = std::cmp::min(next_tick, next_retrieval_instant);
+                    continue;
+                }
+            }
             let required_info = if block_info.header.is_none() {
                 AskForBlocksInfo::Header
             } else if block_info.operation_ids.is_none() {
@@ -683,8 +702,14 @@ impl ProtocolWorker {
                 )
             })
             .collect();
+        let mut total_active_block_req_count: usize = active_block_req_count.values().sum();
 
         for (hash, criteria) in candidate_nodes.into_iter() {
+            // the remaining blocks will be asked for when active requests complete or time out
+            if total_active_block_req_count >= self.config.max_simultaneous_ask_blocks {
+                break;
+            }
+
             // find the best node
             if let Some((_knowledge, best_node, required_info)) = criteria
                 .into_iter()
@@ -707,6 +732,7 @@ impl ProtocolWorker {
                 if let Some(cnt) = active_block_req_count.get_mut(&best_node) {
                     *cnt += 1; // increase the number of actively asked blocks
                 }
+                total_active_block_req_count += 1;
 
                 ask_block_list
                     .entry(best_node)
@@ -7,6 +7,8 @@ use massa_models::{block_id::BlockId, slot::Slot};
 use massa_network_exports::{AskForBlocksInfo, BlockInfoReply, NetworkCommand};
 use massa_protocol_exports::tests::tools;
 use massa_protocol_exports::tests::tools::{asked_list, assert_hash_asked_to_node};
+use massa_protocol_exports::ProtocolConfig;
+use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use serial_test::serial;
 
@@ -646,3 +648,156 @@ async fn test_multiple_blocks_without_a_priori() {
     )
     .await;
 }
+
+#[tokio::test]
+#[serial]
+async fn test_max_simultaneous_ask_blocks() {
+    let protocol_config = ProtocolConfig {
+        max_simultaneous_ask_blocks: 10,
+        ..*tools::PROTOCOL_CONFIG
+    };
+    protocol_test(
+        &protocol_config,
+        async move |mut network_controller,
+                    mut protocol_command_sender,
+                    protocol_manager,
+                    protocol_consensus_event_receiver,
+                    protocol_pool_event_receiver| {
+            let _nodes = tools::create_and_connect_nodes(20, &mut network_controller).await;
+
+            // ask for 100 blocks at once
+            let keypair = KeyPair::generate();
+            let wishlist = (1..=100)
+                .map(|period| {
+                    let block = tools::create_block_with_operations(
+                        &keypair,
+                        Slot::new(period, 0),
+                        Vec::new(),
+                    );
+                    (block.id, None)
+                })
+                .collect();
+            let protocol_command_sender = tokio::task::spawn_blocking(move || {
+                protocol_command_sender
+                    .send_wishlist_delta(wishlist, PreHashSet::<BlockId>::default())
+                    .unwrap();
+                protocol_command_sender
+            })
+            .await
+            .unwrap();
+
+            // the first batch fills the cap, and the following ones (after the ask timeout) never exceed it
+            for round in 0..3 {
+                let asked_count: usize = asked_list(&mut network_controller)
+                    .await
+                    .values()
+                    .map(|list| list.len())
+                    .sum();
+                assert!(asked_count <= 10, "{} blocks asked at once", asked_count);
+                if round == 0 {
+                    assert_eq!(asked_count, 10);
+                }
+            }
+
+            (
+                network_controller,
+                protocol_command_sender,
+                protocol_manager,
+                protocol_consensus_event_receiver,
+                protocol_pool_event_receiver,
+            )
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_block_retrieval_backoff() {
+    let protocol_config = ProtocolConfig {
+        block_retrieval_attempts_before_backoff: 2,
+        ..*tools::PROTOCOL_CONFIG
+    };
+    protocol_test(
+        &protocol_config,
+        async move |mut network_controller,
+                    mut protocol_command_sender,
+                    protocol_manager,
+                    protocol_consensus_event_receiver,
+                    protocol_pool_event_receiver| {
+            let node_a = tools::create_and_connect_nodes(1, &mut network_controller)
+                .await
+                .pop()
+                .unwrap();
+            let _other_nodes = tools::create_and_connect_nodes(2, &mut network_controller).await;
+
+            let op_1 = tools::create_operation_with_expire_period(&node_a.keypair, 5);
+            let op_2 = tools::create_operation_with_expire_period(&node_a.keypair, 5);
+            let op_thread = op_1
+                .content_creator_address
+                .get_thread(protocol_config.thread_count);
+            let block = tools::create_block_with_operations(
+                &node_a.keypair,
+                Slot::new(1, op_thread),
+                vec![op_1.clone(), op_2],
+            );
+
+            // send wishlist
+            let header = block.content.header.clone();
+            let protocol_command_sender = tokio::task::spawn_blocking(move || {
+                protocol_command_sender
+                    .send_wishlist_delta(
+                        vec![(block.id, Some(header))].into_iter().collect(),
+                        PreHashSet::<BlockId>::default(),
+                    )
+                    .unwrap();
+                protocol_command_sender
+            })
+            .await
+            .unwrap();
+
+            let asked_node_filter = |cmd| match cmd {
+                NetworkCommand::AskForBlocks { list } => list
+                    .into_iter()
+                    .find(|(_, asked)| asked.iter().any(|(id, _)| *id == block.id))
+                    .map(|(node_id, _)| node_id),
+                _ => None,
+            };
+
+            // each asked node replies with an operation list that does not match the header
+            for _ in 0..2 {
+                let node_id = network_controller
+                    .wait_command(1000.into(), asked_node_filter)
+                    .await
+                    .expect("block not asked");
+                network_controller
+                    .send_block_info(
+                        node_id,
+                        vec![(block.id, BlockInfoReply::Info(vec![op_1.id]))],
+                    )
+                    .await;
+            }
+
+            // the next retrieval is delayed by `ask_block_timeout` (500 ms)
+            assert!(network_controller
+                .wait_command(300.into(), asked_node_filter)
+                .await
+                .is_none());
+
+            // the block is still wanted: it is asked again once the backoff is over
+            network_controller
+                .wait_command(1000.into(), asked_node_filter)
+                .await
+                .expect("block not asked again after the backoff");
+
+            (
+                network_controller,
+                protocol_command_sender,
+                protocol_manager,
+                protocol_consensus_event_receiver,
+                protocol_pool_event_receiver,
+            )
+        },
+    )
+    .await;
+}
@@ -14,7 +14,7 @@ use crate::protocol_worker::ProtocolWorker;
 use massa_logging::massa_trace;
 use massa_models::{
     node::NodeId,
-    operation::{OperationPrefixIds, SecureShareOperation},
+    operation::{OperationId, OperationPrefixIds, SecureShareOperation},
     prehash::CapacityAllocator,
 };
 use massa_protocol_exports::ProtocolError;
@@ -233,4 +233,27 @@ impl ProtocolWorker {
         }
         Ok(())
     }
+
+    /// Process the reception of a list of operations asked by their full ids,
+    /// replying with the ones we have in storage.
+    pub(crate) async fn on_asked_operations_by_id_received(
+        &mut self,
+        node_id: NodeId,
+        op_ids: Vec<OperationId>,
+    ) -> Result<(), ProtocolError> {
+        let ops: Vec<SecureShareOperation> = {
+            // Scope the lock because of the async call to `send_operations` below.
+            let stored_ops = self.storage.read_operations();
+            op_ids
+                .iter()
+                .filter_map(|id| stored_ops.get(id).cloned())
+                .collect()
+        };
+        if !ops.is_empty() {
+            self.network_command_sender
+                .send_operations(node_id, ops)
+                .await?;
+        }
+        Ok(())
+    }
 }
@@ -6,8 +6,13 @@
 mod error;
 mod signature_impl;
 
+/// Test utils
+#[cfg(any(test, feature = "testing"))]
+pub mod test_exports;
+
 pub use error::MassaSignatureError;
 pub use signature_impl::{
     verify_signature_batch, KeyPair, PublicKey, PublicKeyDeserializer, Signature,
-    SignatureDeserializer, PUBLIC_KEY_SIZE_BYTES, SECRET_KEY_BYTES_SIZE, SIGNATURE_SIZE_BYTES,
+    SignatureDeserializer, HEX_PREFIX, PUBLIC_KEY_SIZE_BYTES, SECRET_KEY_BYTES_SIZE,
+    SIGNATURE_SIZE_BYTES,
 };
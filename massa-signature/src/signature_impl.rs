@@ -25,6 +25,8 @@ pub const PUBLIC_KEY_SIZE_BYTES: usize = ed25519_dalek::PUBLIC_KEY_LENGTH;
 pub const SECRET_KEY_BYTES_SIZE: usize = ed25519_dalek::SECRET_KEY_LENGTH;
 /// Size of a signature
 pub const SIGNATURE_SIZE_BYTES: usize = ed25519_dalek::SIGNATURE_LENGTH;
+/// Prefix of the hexadecimal representation of public keys and signatures
+pub const HEX_PREFIX: &str = "0x";
 /// `KeyPair` is used for signature and decryption
 pub struct KeyPair(ed25519_dalek::Keypair);
 
@@ -374,6 +376,9 @@ impl std::fmt::Debug for PublicKey {
 impl FromStr for PublicKey {
     type Err = MassaSignatureError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(HEX_PREFIX) {
+            return PublicKey::from_hex(s);
+        }
         let mut chars = s.chars();
         match chars.next() {
             Some(prefix) if prefix == PUBLIC_PREFIX => {
@@ -459,6 +464,42 @@ impl PublicKey {
             .map(Self)
             .map_err(|err| MassaSignatureError::ParsingError(err.to_string()))
     }
+
+    /// Serialize a `PublicKey` as a `0x` prefixed hexadecimal string of its raw bytes.
+    ///
+    /// # Example
+    ///  ```
+    /// # use massa_signature::{PublicKey, KeyPair};
+    /// let keypair = KeyPair::generate();
+    ///
+    /// let serialized: String = keypair.get_public_key().to_hex();
+    /// assert!(serialized.starts_with("0x"));
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{}{}", HEX_PREFIX, hex::encode(self.to_bytes()))
+    }
+
+    /// Deserialize a `PublicKey` from a hexadecimal string of its raw bytes.
+    /// The `0x` prefix is optional.
+    ///
+    /// # Example
+    ///  ```
+    /// # use massa_signature::{PublicKey, KeyPair};
+    /// let keypair = KeyPair::generate();
+    ///
+    /// let serialized = keypair.get_public_key().to_hex();
+    /// let deserialized: PublicKey = PublicKey::from_hex(&serialized).unwrap();
+    /// assert_eq!(keypair.get_public_key(), deserialized);
+    /// ```
+    pub fn from_hex(data: &str) -> Result<PublicKey, MassaSignatureError> {
+        let data = data.strip_prefix(HEX_PREFIX).unwrap_or(data);
+        let decoded = hex::decode(data).map_err(|err| {
+            MassaSignatureError::ParsingError(format!("public key hex parsing error: {}", err))
+        })?;
+        PublicKey::from_bytes(&decoded.try_into().map_err(|_| {
+            MassaSignatureError::ParsingError("Public key not long enough".to_string())
+        })?)
+    }
 }
 
 /// Serializer for `Signature`
@@ -598,7 +639,11 @@ impl std::fmt::Display for Signature {
 impl FromStr for Signature {
     type Err = MassaSignatureError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Signature::from_bs58_check(s)
+        if s.starts_with(HEX_PREFIX) {
+            Signature::from_hex(s)
+        } else {
+            Signature::from_bs58_check(s)
+        }
     }
 }
 
@@ -620,6 +665,48 @@ impl Signature {
         bs58::encode(self.to_bytes()).with_check().into_string()
     }
 
+    /// Serialize a `Signature` as a `0x` prefixed hexadecimal string of its raw bytes.
+    ///
+    /// # Example
+    ///  ```
+    /// # use massa_signature::KeyPair;
+    /// # use massa_hash::Hash;
+    /// let keypair = KeyPair::generate();
+    /// let data = Hash::compute_from("Hello World!".as_bytes());
+    /// let signature = keypair.sign(&data).unwrap();
+    ///
+    /// let serialized: String = signature.to_hex();
+    /// assert!(serialized.starts_with("0x"));
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{}{}", HEX_PREFIX, hex::encode(self.to_bytes()))
+    }
+
+    /// Deserialize a `Signature` from a hexadecimal string of its raw bytes.
+    /// The `0x` prefix is optional.
+    ///
+    /// # Example
+    ///  ```
+    /// # use massa_signature::{KeyPair, Signature};
+    /// # use massa_hash::Hash;
+    /// let keypair = KeyPair::generate();
+    /// let data = Hash::compute_from("Hello World!".as_bytes());
+    /// let signature = keypair.sign(&data).unwrap();
+    ///
+    /// let serialized = signature.to_hex();
+    /// let deserialized: Signature = Signature::from_hex(&serialized).unwrap();
+    /// assert_eq!(signature, deserialized);
+    /// ```
+    pub fn from_hex(data: &str) -> Result<Signature, MassaSignatureError> {
+        let data = data.strip_prefix(HEX_PREFIX).unwrap_or(data);
+        let decoded = hex::decode(data).map_err(|err| {
+            MassaSignatureError::ParsingError(format!("signature hex parsing error: {}", err))
+        })?;
+        Signature::from_bytes(&decoded.try_into().map_err(|err| {
+            MassaSignatureError::ParsingError(format!("signature hex parsing error: {:?}", err))
+        })?)
+    }
+
     /// Serialize a Signature as bytes.
     ///
     /// # Example
@@ -955,4 +1042,50 @@ mod tests {
             serde_json::from_str(&serialized).expect("could not deserialize signature key");
         assert_eq!(signature, deserialized);
     }
+
+    #[test]
+    #[serial]
+    fn test_public_key_hex_and_bs58() {
+        let keypair = KeyPair::generate();
+        let public_key = keypair.get_public_key();
+        let from_bs58 = PublicKey::from_str(&public_key.to_string()).unwrap();
+        let from_hex = PublicKey::from_str(&public_key.to_hex()).unwrap();
+        assert_eq!(from_bs58, public_key);
+        assert_eq!(from_hex, public_key);
+        assert_eq!(from_hex.to_string(), public_key.to_string());
+        assert!(PublicKey::from_str("0xzz").is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_signature_hex_and_bs58() {
+        let keypair = KeyPair::generate();
+        let hash = Hash::compute_from("Hello World!".as_bytes());
+        let signature = keypair.sign(&hash).unwrap();
+        let from_bs58 = Signature::from_str(&signature.to_bs58_check()).unwrap();
+        let from_hex = Signature::from_str(&signature.to_hex()).unwrap();
+        assert_eq!(from_bs58, signature);
+        assert_eq!(from_hex, signature);
+        assert_eq!(from_hex.to_string(), signature.to_string());
+        assert!(Signature::from_hex(&keypair.get_public_key().to_hex()).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_deterministic_keypair() {
+        use crate::test_exports::deterministic_keypair;
+
+        assert_eq!(
+            deterministic_keypair(7).get_public_key(),
+            deterministic_keypair(7).get_public_key()
+        );
+        assert_eq!(
+            deterministic_keypair(7).to_string(),
+            deterministic_keypair(7).to_string()
+        );
+        assert_ne!(
+            deterministic_keypair(7).get_public_key(),
+            deterministic_keypair(8).get_public_key()
+        );
+    }
 }
@@ -0,0 +1,3 @@
+mod tools;
+
+pub use tools::*;
@@ -0,0 +1,13 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+use crate::{KeyPair, SECRET_KEY_BYTES_SIZE};
+use massa_hash::Hash;
+
+/// Returns a keypair that is always the same for a given `index`,
+/// so that the addresses generated in tests are reproducible.
+pub fn deterministic_keypair(index: u64) -> KeyPair {
+    let mut seed = b"massa_test_keypair".to_vec();
+    seed.extend(index.to_be_bytes());
+    let secret: [u8; SECRET_KEY_BYTES_SIZE] = Hash::compute_from(&seed).into_bytes();
+    KeyPair::from_bytes(&secret).expect("could not create deterministic keypair")
+}
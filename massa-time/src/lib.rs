@@ -17,9 +17,14 @@ use std::{
     convert::{TryFrom, TryInto},
     str::FromStr,
 };
-use time::format_description::well_known::Rfc3339;
+use time::format_description::{well_known::Rfc3339, FormatItem};
+use time::macros::format_description;
 use time::OffsetDateTime;
 
+/// RFC 3339 UTC format with millisecond precision
+const RFC3339_MILLIS_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z");
+
 /// Time structure used everywhere.
 /// milliseconds since 01/01/1970.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -421,6 +426,45 @@ impl MassaTime {
         naive.format(&Rfc3339).unwrap()
     }
 
+    /// Formats the time as an RFC 3339 UTC timestamp, keeping the millisecond precision.
+    /// ```
+    /// # use massa_time::*;
+    /// let massa_time = MassaTime::from(1_640_995_200_042);
+    /// assert_eq!(massa_time.to_rfc3339().unwrap(), "2022-01-01T00:00:00.042Z");
+    /// assert_eq!(MassaTime::from(0).to_rfc3339().unwrap(), "1970-01-01T00:00:00.000Z");
+    /// ```
+    pub fn to_rfc3339(self) -> Result<String, TimeError> {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(self.0) * 1_000_000)
+            .map_err(|_| TimeError::ConversionError)?
+            .format(RFC3339_MILLIS_FORMAT)
+            .map_err(|_| TimeError::ConversionError)
+    }
+
+    /// Parses an RFC 3339 timestamp, such as the ones produced by `to_utc_string` or `to_rfc3339`.
+    /// Any UTC offset is accepted. Precision below the millisecond is truncated.
+    /// ```
+    /// # use massa_time::*;
+    /// let massa_time = MassaTime::from(1_640_995_200_042);
+    /// assert_eq!(MassaTime::from_utc_string(&massa_time.to_rfc3339().unwrap()).unwrap(), massa_time);
+    /// let massa_time = MassaTime::from(1_640_995_200_000);
+    /// assert_eq!(MassaTime::from_utc_string(&massa_time.to_utc_string()).unwrap(), massa_time);
+    /// assert_eq!(
+    ///     MassaTime::from_utc_string("2022-01-01T01:00:00.042999+01:00").unwrap(),
+    ///     MassaTime::from(1_640_995_200_042)
+    /// );
+    /// assert!(MassaTime::from_utc_string("1969-12-31T23:59:59Z").is_err());
+    /// assert!(MassaTime::from_utc_string("2022-01-01").is_err());
+    /// ```
+    pub fn from_utc_string(s: &str) -> Result<Self, TimeError> {
+        let millis = OffsetDateTime::parse(s, &Rfc3339)
+            .map_err(|_| TimeError::ConversionError)?
+            .unix_timestamp_nanos()
+            .div_euclid(1_000_000);
+        u64::try_from(millis)
+            .map(MassaTime)
+            .map_err(|_| TimeError::ConversionError)
+    }
+
     /// ```
     /// # use massa_time::*;
     /// let massa_time = MassaTime::from(1000 * ( 8 * 24*60*60 + 1 * 60*60 + 3 * 60 + 6 ));